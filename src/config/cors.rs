@@ -20,6 +20,8 @@ pub fn init_cors() -> CorsLayer {
             HeaderName::from_static("content-type"),
             HeaderName::from_static("authorization"),
             HeaderName::from_static("x-csrf-token"),
+            HeaderName::from_static("x-poll-access-token"),
+            HeaderName::from_static("last-event-id"),
             HeaderName::from_static("cookie"),
         ])
         .allow_origin(
@@ -1,4 +1,7 @@
-use mongodb::{Client, Database};
+use mongodb::{
+    error::{Error, ErrorKind, WriteError, WriteFailure},
+    Client, Database,
+};
 use std::{env, sync::Arc};
 use tracing::{error, info};
 
@@ -25,3 +28,11 @@ pub async fn init_database() -> mongodb::error::Result<Arc<Database>> {
 
     Ok(Arc::new(database))
 }
+
+/// Whether the write failed because it would break a unique index
+pub fn is_duplicate_key(error: &Error) -> bool {
+    matches!(
+        error.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(WriteError { code: 11000, .. }))
+    )
+}
@@ -0,0 +1,15 @@
+/// Base URL of the web app, used when building links to polls
+pub fn frontend_url() -> String {
+    std::env::var("FRONTEND_URL")
+        .unwrap_or_else(|_| "https://votx.vercel.app".to_string())
+        .trim_end_matches('/')
+        .to_string()
+}
+
+pub fn poll_link(poll_id: &str) -> String {
+    format!("{}/poll/{}", frontend_url(), poll_id)
+}
+
+pub fn join_link(join_code: &str) -> String {
+    format!("{}/join/{}", frontend_url(), join_code)
+}
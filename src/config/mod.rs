@@ -1,5 +1,8 @@
 pub mod cors;
 pub mod db;
+pub mod frontend;
 pub mod logger;
+pub mod proxy;
 pub mod session;
 pub mod startup;
+pub mod validation;
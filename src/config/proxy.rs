@@ -0,0 +1,25 @@
+use std::{net::IpAddr, sync::OnceLock};
+
+use tracing::warn;
+
+/// Addresses of the reverse proxies in front of the server, from the comma separated
+/// TRUSTED_PROXIES. Only their `X-Forwarded-For` headers are believed.
+pub fn trusted_proxies() -> &'static [IpAddr] {
+    static PROXIES: OnceLock<Vec<IpAddr>> = OnceLock::new();
+
+    PROXIES.get_or_init(|| {
+        std::env::var("TRUSTED_PROXIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|proxy| !proxy.is_empty())
+            .filter_map(|proxy| match proxy.parse::<IpAddr>() {
+                Ok(ip) => Some(ip),
+                Err(_) => {
+                    warn!("Ignoring invalid trusted proxy {}", proxy);
+                    None
+                }
+            })
+            .collect()
+    })
+}
@@ -3,6 +3,11 @@ use std::sync::Arc;
 use tokio::sync::Mutex;
 use webauthn_rs::prelude::*;
 
+use crate::services::{
+    attempt_limiter::AttemptLimiter, live_results::LiveResultsHub,
+    presentation_hub::PresentationHub,
+};
+
 /*
  * Webauthn RS server side app state and setup  code.
  */
@@ -26,6 +31,16 @@ pub struct AppState {
     pub webauthn: Arc<Webauthn>,
     // This needs mutability, so does require a mutex.
     pub users: Arc<Mutex<Data>>,
+    // Failed poll password attempts per poll and client
+    pub unlock_attempts: AttemptLimiter,
+    // Guest tokens issued per poll and client
+    pub guest_token_limiter: AttemptLimiter,
+    // Reactions sent per user
+    pub reaction_limiter: AttemptLimiter,
+    // Pushes result updates to live viewers
+    pub live_results: LiveResultsHub,
+    // Pushes presenter actions to presentation audiences
+    pub presentations: PresentationHub,
 }
 
 impl AppState {
@@ -52,6 +67,14 @@ impl AppState {
             keys: HashMap::new(),
         }));
 
-        AppState { webauthn, users }
+        AppState {
+            webauthn,
+            users,
+            unlock_attempts: AttemptLimiter::for_poll_unlock(),
+            guest_token_limiter: AttemptLimiter::for_guest_tokens(),
+            reaction_limiter: AttemptLimiter::for_reactions(),
+            live_results: LiveResultsHub::default(),
+            presentations: PresentationHub::default(),
+        }
     }
 }
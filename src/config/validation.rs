@@ -0,0 +1,65 @@
+use tracing::info;
+
+/// Limits enforced on incoming request bodies, read once at startup
+#[derive(Debug, Clone, Copy)]
+pub struct ValidationLimits {
+    pub max_name_length: usize,
+    pub max_option_length: usize,
+    pub min_options: usize,
+    pub max_options: usize,
+    pub min_username_length: usize,
+    pub max_username_length: usize,
+    pub min_password_length: usize,
+    pub max_password_length: usize,
+    pub max_presentation_polls: usize,
+    pub max_question_length: usize,
+    pub max_comment_length: usize,
+}
+
+impl Default for ValidationLimits {
+    fn default() -> Self {
+        ValidationLimits {
+            max_name_length: 200,
+            max_option_length: 100,
+            min_options: 2,
+            max_options: 20,
+            min_username_length: 3,
+            max_username_length: 32,
+            min_password_length: 4,
+            max_password_length: 128,
+            max_presentation_polls: 50,
+            max_question_length: 300,
+            max_comment_length: 2000,
+        }
+    }
+}
+
+impl ValidationLimits {
+    /// Every limit can be overridden with its own environment variable
+    pub fn from_env() -> Self {
+        let defaults = ValidationLimits::default();
+        let read = |key: &str, default: usize| {
+            std::env::var(key)
+                .ok()
+                .and_then(|value| value.parse::<usize>().ok())
+                .unwrap_or(default)
+        };
+
+        let limits = ValidationLimits {
+            max_name_length: read("POLL_NAME_MAX_LENGTH", defaults.max_name_length),
+            max_option_length: read("POLL_OPTION_MAX_LENGTH", defaults.max_option_length),
+            min_options: read("POLL_MIN_OPTIONS", defaults.min_options),
+            max_options: read("POLL_MAX_OPTIONS", defaults.max_options),
+            min_username_length: read("USERNAME_MIN_LENGTH", defaults.min_username_length),
+            max_username_length: read("USERNAME_MAX_LENGTH", defaults.max_username_length),
+            min_password_length: read("POLL_PASSWORD_MIN_LENGTH", defaults.min_password_length),
+            max_password_length: read("POLL_PASSWORD_MAX_LENGTH", defaults.max_password_length),
+            max_presentation_polls: read("PRESENTATION_MAX_POLLS", defaults.max_presentation_polls),
+            max_question_length: read("QUESTION_MAX_LENGTH", defaults.max_question_length),
+            max_comment_length: read("COMMENT_MAX_LENGTH", defaults.max_comment_length),
+        };
+
+        info!("Validation limits:: {:?}", limits);
+        limits
+    }
+}
@@ -11,7 +11,7 @@ use crate::{
         registration_state_repository::RegistrationStateRepository,
         user_repository::{self, UserRepository},
     },
-    utils::jwt::create_token,
+    utils::{jwt::create_token, validation::ValidatedQuery},
 };
 use axum::{
     extract::{Extension, Json, Path, Query},
@@ -51,7 +51,7 @@ fn get_jwt_secret() -> Result<Vec<u8>, AppError> {
 pub async fn initiate_register(
     Extension(app_state): Extension<AppState>,
     Extension(db): Extension<Arc<Database>>,
-    Query(query): Query<RegisterQuery>,
+    ValidatedQuery(query): ValidatedQuery<RegisterQuery>,
 ) -> Result<impl IntoResponse, AppError> {
     info!("Starting register");
 
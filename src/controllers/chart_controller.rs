@@ -0,0 +1,84 @@
+use axum::{
+    extract::Path,
+    http::header::{CACHE_CONTROL, CONTENT_TYPE},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    controllers::poll_controller::{get_user_id_from_token, verify_live_results_access},
+    dtos::requests::{ChartQueryParams, ImageFormat},
+    error::AppError,
+    repositories::poll_repository::PollRepository,
+    utils::{
+        chart::{render_png, render_svg, ChartOptions},
+        poll_access::PollAccessToken,
+        validation::ValidatedQuery,
+    },
+};
+
+//*GET:: api/polls/poll_id/results/chart
+pub async fn get_poll_results_chart(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedQuery(query): ValidatedQuery<ChartQueryParams>,
+) -> Result<Response, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_live_results_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let poll = poll_repository.get_poll_results(poll_id).await?;
+    let svg = render_svg(
+        &poll,
+        &ChartOptions {
+            kind: query.chart.unwrap_or_default(),
+            theme: query.theme.unwrap_or_default(),
+            labels: query.labels.unwrap_or_default(),
+            width: query.width.unwrap_or(800),
+            height: query.height.unwrap_or(500),
+        },
+    );
+
+    // Results keep changing, and aren't for everyone to see
+    let response = match query.format.unwrap_or_default() {
+        ImageFormat::Svg => (
+            [
+                (CONTENT_TYPE, "image/svg+xml"),
+                (CACHE_CONTROL, "private, no-cache"),
+            ],
+            svg,
+        )
+            .into_response(),
+        ImageFormat::Png => {
+            // Rasterising is CPU bound, so it runs off the async workers
+            let png = tokio::task::spawn_blocking(move || render_png(&svg))
+                .await
+                .map_err(|_| AppError::Unknown)??;
+            (
+                [
+                    (CONTENT_TYPE, "image/png"),
+                    (CACHE_CONTROL, "private, no-cache"),
+                ],
+                png,
+            )
+                .into_response()
+        }
+    };
+
+    Ok(response)
+}
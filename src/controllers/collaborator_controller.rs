@@ -0,0 +1,161 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::InviteCollaboratorDTO,
+        responses::{ApiResponse, CollaboratorInviteDTO, PollCollaboratorDTO},
+    },
+    error::AppError,
+    models::poll::PollAction,
+    repositories::poll_repository::PollRepository,
+    utils::validation::ValidatedJson,
+};
+
+//*GET:: api/polls/poll_id/collaborators
+pub async fn get_poll_collaborators(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PollCollaboratorDTO>>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageCollaborators)
+        .await?;
+    let collaborators = poll_repository.get_collaborators(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll collaborators retrieved successfully"),
+        data: Some(collaborators),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/collaborators
+pub async fn invite_collaborator(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<InviteCollaboratorDTO>,
+) -> Result<Json<ApiResponse<Vec<PollCollaboratorDTO>>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageCollaborators)
+        .await?;
+    poll_repository
+        .invite_collaborator(&poll_id, &payload.userId, payload.role, &user_id)
+        .await?;
+    let collaborators = poll_repository.get_collaborators(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Collaborator invited successfully"),
+        data: Some(collaborators),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/polls/poll_id/collaborators/user_id
+pub async fn remove_collaborator(
+    Extension(db): Extension<Arc<Database>>,
+    Path((poll_id, collaborator_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Collaborators may always step down
+    if collaborator_id != user_id {
+        poll_repository
+            .verify_poll_permission(&poll_id, &user_id, PollAction::ManageCollaborators)
+            .await?;
+    }
+    poll_repository
+        .remove_collaborator(&poll_id, &collaborator_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Collaborator removed successfully"),
+        data: Some(String::from("Collaborator removed successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/collaborators/accept
+pub async fn accept_collaboration(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .respond_to_invitation(&poll_id, &user_id, true)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Invitation accepted successfully"),
+        data: Some(String::from("Invitation accepted successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/collaborators/decline
+pub async fn decline_collaboration(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .respond_to_invitation(&poll_id, &user_id, false)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Invitation declined successfully"),
+        data: Some(String::from("Invitation declined successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/invitations
+pub async fn get_collaboration_invitations(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<CollaboratorInviteDTO>>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let invitations = poll_repository.get_pending_invitations(&user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Invitations fetched successfully"),
+        data: Some(invitations),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
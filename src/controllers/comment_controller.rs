@@ -0,0 +1,257 @@
+use axum::{extract::Path, http, Extension, Json};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    config::startup::AppState,
+    controllers::{
+        password_controller::verify_poll_unlocked, poll_controller::get_user_id_from_token,
+    },
+    dtos::{
+        requests::{CommentQueryParams, CreateCommentDTO, EditCommentDTO},
+        responses::{ApiResponse, CommentPageDTO, CommentResponseDTO, LiveEvent},
+    },
+    error::{AppError, PollsError},
+    models::poll::PollAction,
+    repositories::{comment_repository::CommentRepository, poll_repository::PollRepository},
+    utils::{
+        poll_access::PollAccessToken,
+        validation::{ValidatedJson, ValidatedQuery},
+    },
+};
+
+const DEFAULT_PAGE_SIZE: u64 = 20;
+
+// Helper function to check that the user can see the poll, and so take part in its discussion
+async fn verify_discussion_access(
+    poll_repository: &PollRepository,
+    poll_id: &str,
+    user_id: &str,
+    access_token: Option<&str>,
+) -> Result<(), AppError> {
+    poll_repository.verify_poll_access(poll_id, user_id).await?;
+    verify_poll_unlocked(poll_repository, poll_id, user_id, access_token).await
+}
+
+// Helper function to load a page of comments, top level ones when `parent_id` is unset
+async fn comment_page(
+    comment_repository: &CommentRepository,
+    poll_id: &str,
+    parent_id: Option<&str>,
+    query: CommentQueryParams,
+) -> Result<CommentPageDTO, AppError> {
+    let page = query.page.unwrap_or(1);
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE);
+    let (comments, total) = comment_repository
+        .get_comments(poll_id, parent_id, page, limit)
+        .await?;
+
+    Ok(CommentPageDTO {
+        comments: comments.iter().map(|c| c.to_response_dto()).collect(),
+        page,
+        limit,
+        total,
+    })
+}
+
+//*GET:: api/polls/poll_id/comments
+pub async fn get_poll_comments(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedQuery(query): ValidatedQuery<CommentQueryParams>,
+) -> Result<Json<ApiResponse<CommentPageDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db.clone());
+    let comment_repository = CommentRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_discussion_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let page = comment_page(&comment_repository, &poll_id, None, query).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Comments retrieved successfully"),
+        data: Some(page),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/comments/comment_id/replies
+pub async fn get_comment_replies(
+    Extension(db): Extension<Arc<Database>>,
+    Path((poll_id, comment_id)): Path<(String, String)>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedQuery(query): ValidatedQuery<CommentQueryParams>,
+) -> Result<Json<ApiResponse<CommentPageDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db.clone());
+    let comment_repository = CommentRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_discussion_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+    comment_repository
+        .find_comment(&poll_id, &comment_id)
+        .await?;
+
+    let page = comment_page(&comment_repository, &poll_id, Some(&comment_id), query).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Replies retrieved successfully"),
+        data: Some(page),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/comments
+pub async fn post_poll_comment(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<CreateCommentDTO>,
+) -> Result<Json<ApiResponse<CommentResponseDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db.clone());
+    let comment_repository = CommentRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_discussion_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let comment = comment_repository
+        .create_comment(&poll_id, payload.parentId, user_id, payload.text)
+        .await?
+        .to_response_dto();
+    app_state.live_results.notify(
+        &poll_id,
+        LiveEvent::CommentAdded {
+            comment: comment.clone(),
+        },
+    );
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Comment posted successfully"),
+        data: Some(comment),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?PATCH:: api/polls/poll_id/comments/comment_id
+pub async fn edit_poll_comment(
+    Extension(db): Extension<Arc<Database>>,
+    Path((poll_id, comment_id)): Path<(String, String)>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<EditCommentDTO>,
+) -> Result<Json<ApiResponse<CommentResponseDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db.clone());
+    let comment_repository = CommentRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_discussion_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let comment = comment_repository
+        .find_comment(&poll_id, &comment_id)
+        .await?;
+    if comment.isDeleted {
+        return Err(AppError::Poll(PollsError::CommentNotFound));
+    }
+    // Only the author can put words in their mouth
+    if comment.authorId != user_id {
+        return Err(AppError::Poll(PollsError::Unauthorized));
+    }
+
+    comment_repository
+        .update_text(&comment_id, payload.text)
+        .await?;
+    let comment = comment_repository
+        .find_comment(&poll_id, &comment_id)
+        .await?
+        .to_response_dto();
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Comment updated successfully"),
+        data: Some(comment),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/polls/poll_id/comments/comment_id
+pub async fn delete_poll_comment(
+    Extension(db): Extension<Arc<Database>>,
+    Path((poll_id, comment_id)): Path<(String, String)>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<()>>, AppError> {
+    let poll_repository = PollRepository::new(db.clone());
+    let comment_repository = CommentRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_discussion_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let comment = comment_repository
+        .find_comment(&poll_id, &comment_id)
+        .await?;
+    if comment.isDeleted {
+        return Err(AppError::Poll(PollsError::CommentNotFound));
+    }
+    // Authors take back their own comments, the owner and moderators anyone's
+    if comment.authorId != user_id {
+        poll_repository
+            .verify_poll_permission(&poll_id, &user_id, PollAction::ModerateComments)
+            .await?;
+    }
+
+    comment_repository.delete_comment(&comment_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Comment deleted successfully"),
+        data: None,
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
@@ -0,0 +1,177 @@
+use axum::{
+    extract::{ConnectInfo, Path, Query},
+    http::{HeaderMap, StatusCode},
+    Extension, Json,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::{net::SocketAddr, sync::Arc};
+use uuid::Uuid;
+
+use crate::{
+    config::startup::AppState,
+    controllers::password_controller::verify_poll_unlocked,
+    dtos::{
+        requests::VoteQueryParam,
+        responses::{ApiResponse, GuestTokenResponseDTO, PollResponseDTO},
+    },
+    error::{AppError, JwtError, PollsError},
+    repositories::poll_repository::PollRepository,
+    utils::{
+        fingerprint::{client_ip, device_fingerprint},
+        jwt::{create_guest_token, verify_guest_token},
+        poll_access::PollAccessToken,
+    },
+};
+
+fn jwt_secret() -> Result<Vec<u8>, AppError> {
+    std::env::var("JWT_SECRET")
+        .map(|s| s.into_bytes())
+        .map_err(|_| AppError::JwtError(JwtError::MissingSecret))
+}
+
+// Helper function to extract the guest id from a guest token issued for this poll
+fn get_guest_id_from_token(token: &str, poll_id: &str) -> Result<String, AppError> {
+    let claims = verify_guest_token(token, &jwt_secret()?)
+        .map_err(|_| AppError::Poll(PollsError::InvalidGuestToken))?;
+
+    if claims.poll != poll_id {
+        return Err(AppError::Poll(PollsError::InvalidGuestToken));
+    }
+
+    Ok(claims.sub)
+}
+
+//?POST:: api/polls/poll_id/guest-token
+pub async fn issue_guest_token(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<GuestTokenResponseDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    // Each token is a fresh guest, so a client can only get a few per poll
+    app_state
+        .guest_token_limiter
+        .attempt(&format!("{}:{}", poll_id, client_ip(&headers, addr)))?;
+    let poll = poll_repository.get_guest_poll(&poll_id).await?;
+    verify_poll_unlocked(&poll_repository, &poll_id, "", access_token.as_deref()).await?;
+
+    if poll.isClosed {
+        return Err(AppError::Poll(PollsError::PollClosed));
+    }
+    if poll.endDate.timestamp_millis() <= Utc::now().timestamp_millis() {
+        return Err(AppError::Poll(PollsError::PollEnded));
+    }
+
+    // The token is useless once the poll ends, so it expires with it
+    let guest_id = format!("guest:{}", Uuid::new_v4());
+    let guest_token = create_guest_token(
+        &guest_id,
+        &poll_id,
+        poll.endDate.timestamp_millis() / 1000,
+        &jwt_secret()?,
+    )?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Guest token issued successfully"),
+        data: Some(GuestTokenResponseDTO {
+            poll_id,
+            guest_id,
+            guest_token,
+            expires_at: poll.endDate.to_string(),
+        }),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/guest-vote
+#[allow(clippy::too_many_arguments)] // One per extractor
+pub async fn cast_guest_vote(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    Query(query): Query<VoteQueryParam>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let guest_id = get_guest_id_from_token(authorization.token(), &poll_id)?;
+    let fingerprint = device_fingerprint(&poll_id, &headers, addr);
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &guest_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let poll = poll_repository
+        .cast_guest_vote(
+            poll_id.clone(),
+            query.optionId,
+            guest_id.clone(),
+            fingerprint,
+        )
+        .await?;
+    app_state.live_results.publish(&poll);
+    let updated_poll = poll_repository
+        .get_poll_for_user(&poll_id, &guest_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Vote cast successfully"),
+        data: Some(updated_poll),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/guest-can-vote
+pub async fn can_guest_vote(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> Result<Json<ApiResponse<bool>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let guest_id = get_guest_id_from_token(authorization.token(), &poll_id)?;
+    let fingerprint = device_fingerprint(&poll_id, &headers, addr);
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &guest_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let can_vote = poll_repository
+        .can_guest_vote(&poll_id, &guest_id, &fingerprint)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: if can_vote {
+            StatusCode::OK
+        } else {
+            StatusCode::FORBIDDEN
+        }
+        .as_u16() as i32,
+        message: if can_vote { "Can vote" } else { "Cannot vote" }.to_string(),
+        data: Some(can_vote),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
@@ -0,0 +1,294 @@
+use axum::{
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    response::Response,
+    Extension,
+};
+use mongodb::Database;
+use std::{collections::HashMap, pin::Pin, sync::Arc, time::Duration};
+use tokio::time::{interval_at, Instant};
+use tokio_stream::{Stream, StreamExt, StreamMap};
+use tracing::{info, warn};
+
+use crate::{
+    config::startup::AppState,
+    controllers::poll_controller::{react_to_poll, verify_live_results_access, vote_on_poll},
+    dtos::{
+        requests::LiveClientMessage,
+        responses::{LiveEvent, LiveResults, LiveServerMessage, LiveUpdate},
+    },
+    error::AppError,
+    repositories::poll_repository::PollRepository,
+    services::live_results::LiveResultsHub,
+    utils::jwt::Claims,
+};
+
+const MAX_SUBSCRIPTIONS: usize = 20;
+// How often subscriptions are checked against the poll's current settings, so revoked
+// collaborators, hidden results or a new password stop the events
+const ACCESS_RECHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+type EventStream = Pin<Box<dyn Stream<Item = LiveUpdate> + Send>>;
+
+/// Polls a connection is subscribed to, with the access tokens they were unlocked with
+#[derive(Default)]
+struct Subscriptions {
+    streams: StreamMap<String, EventStream>,
+    access_tokens: HashMap<String, String>,
+}
+
+//*GET:: api/polls/ws
+pub async fn live_polls_socket(
+    ws: WebSocketUpgrade,
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Extension(claims): Extension<Claims>,
+) -> Response {
+    let poll_repository = PollRepository::new(db);
+
+    ws.on_upgrade(move |socket| handle_socket(socket, poll_repository, app_state, claims.sub))
+}
+
+async fn handle_socket(
+    mut socket: WebSocket,
+    poll_repository: PollRepository,
+    app_state: AppState,
+    user_id: String,
+) {
+    info!("User {} connected to the live polls socket", user_id);
+    let mut subscriptions = Subscriptions::default();
+    let mut access_checks = interval_at(
+        Instant::now() + ACCESS_RECHECK_INTERVAL,
+        ACCESS_RECHECK_INTERVAL,
+    );
+
+    'connection: loop {
+        let replies = tokio::select! {
+            message = socket.recv() => match message {
+                Some(Ok(Message::Text(text))) => {
+                    vec![
+                        handle_message(
+                            &text,
+                            &mut subscriptions,
+                            &poll_repository,
+                            &app_state,
+                            &user_id,
+                        )
+                        .await,
+                    ]
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // Pings are answered by axum
+                Some(Ok(_)) => continue,
+            },
+            Some((_, event)) = subscriptions.streams.next(), if !subscriptions.streams.is_empty() => {
+                vec![LiveServerMessage::Event { event }]
+            }
+            _ = access_checks.tick() => {
+                recheck_access(&mut subscriptions, &poll_repository, &user_id).await
+            }
+        };
+
+        for reply in replies {
+            let Ok(reply) = serde_json::to_string(&reply) else {
+                continue;
+            };
+            if socket.send(Message::Text(reply.into())).await.is_err() {
+                break 'connection;
+            }
+        }
+    }
+
+    info!("User {} disconnected from the live polls socket", user_id);
+}
+
+async fn handle_message(
+    text: &str,
+    subscriptions: &mut Subscriptions,
+    poll_repository: &PollRepository,
+    app_state: &AppState,
+    user_id: &str,
+) -> LiveServerMessage {
+    let message = match serde_json::from_str::<LiveClientMessage>(text) {
+        Ok(message) => message,
+        Err(e) => {
+            warn!("Invalid live polls message from {}: {}", user_id, e);
+            return LiveServerMessage::Error {
+                request_id: None,
+                poll_id: None,
+                message: format!("Invalid message: {}", e),
+            };
+        }
+    };
+
+    match message {
+        LiveClientMessage::Subscribe {
+            pollId,
+            accessToken,
+        } => {
+            if !subscriptions.streams.contains_key(&pollId)
+                && subscriptions.streams.len() >= MAX_SUBSCRIPTIONS
+            {
+                return LiveServerMessage::Error {
+                    request_id: None,
+                    poll_id: Some(pollId),
+                    message: format!("At most {} polls can be watched at once", MAX_SUBSCRIPTIONS),
+                };
+            }
+
+            let subscribed = watch_poll(
+                poll_repository,
+                &app_state.live_results,
+                &pollId,
+                user_id,
+                accessToken.as_deref(),
+            )
+            .await;
+
+            match subscribed {
+                Ok(stream) => {
+                    subscriptions.streams.insert(pollId.clone(), stream);
+                    if let Some(access_token) = accessToken {
+                        subscriptions
+                            .access_tokens
+                            .insert(pollId.clone(), access_token);
+                    }
+                    LiveServerMessage::Subscribed { poll_id: pollId }
+                }
+                Err(e) => LiveServerMessage::Error {
+                    request_id: None,
+                    poll_id: Some(pollId),
+                    message: e.to_string(),
+                },
+            }
+        }
+        LiveClientMessage::Unsubscribe { pollId } => {
+            subscriptions.streams.remove(&pollId);
+            subscriptions.access_tokens.remove(&pollId);
+            LiveServerMessage::Unsubscribed { poll_id: pollId }
+        }
+        LiveClientMessage::Vote {
+            pollId,
+            optionId,
+            requestId,
+            accessToken,
+        } => {
+            let access_token = accessToken
+                .as_deref()
+                .or(subscriptions.access_tokens.get(&pollId).map(String::as_str));
+
+            match vote_on_poll(
+                poll_repository,
+                &app_state.live_results,
+                &pollId,
+                optionId,
+                user_id,
+                access_token,
+            )
+            .await
+            {
+                Ok(poll) => LiveServerMessage::VoteAck {
+                    request_id: requestId,
+                    poll: Box::new(poll),
+                },
+                Err(e) => LiveServerMessage::Error {
+                    request_id: requestId,
+                    poll_id: Some(pollId),
+                    message: e.to_string(),
+                },
+            }
+        }
+        LiveClientMessage::React {
+            pollId,
+            reaction,
+            accessToken,
+        } => {
+            let access_token = accessToken
+                .as_deref()
+                .or(subscriptions.access_tokens.get(&pollId).map(String::as_str));
+
+            match react_to_poll(
+                poll_repository,
+                app_state,
+                &pollId,
+                reaction,
+                user_id,
+                access_token,
+            )
+            .await
+            {
+                Ok(()) => LiveServerMessage::Reacted { poll_id: pollId },
+                Err(e) => LiveServerMessage::Error {
+                    request_id: None,
+                    poll_id: Some(pollId),
+                    message: e.to_string(),
+                },
+            }
+        }
+    }
+}
+
+/// Drops the subscriptions the user may not watch anymore, telling them why
+async fn recheck_access(
+    subscriptions: &mut Subscriptions,
+    poll_repository: &PollRepository,
+    user_id: &str,
+) -> Vec<LiveServerMessage> {
+    let mut replies = Vec::new();
+    let poll_ids: Vec<String> = subscriptions.streams.keys().cloned().collect();
+
+    for poll_id in poll_ids {
+        let access_token = subscriptions
+            .access_tokens
+            .get(&poll_id)
+            .map(String::as_str);
+        match verify_live_results_access(poll_repository, &poll_id, user_id, access_token).await {
+            Ok(()) => {}
+            // Keep watching through database hiccups, the next check decides
+            Err(AppError::DatabaseError(e)) => {
+                warn!("Could not recheck access to poll {}: {}", poll_id, e);
+            }
+            Err(e) => {
+                info!("User {} lost access to live poll {}", user_id, poll_id);
+                subscriptions.streams.remove(&poll_id);
+                subscriptions.access_tokens.remove(&poll_id);
+                replies.push(LiveServerMessage::Error {
+                    request_id: None,
+                    poll_id: Some(poll_id.clone()),
+                    message: e.to_string(),
+                });
+                replies.push(LiveServerMessage::Unsubscribed { poll_id });
+            }
+        }
+    }
+
+    replies
+}
+
+async fn watch_poll(
+    poll_repository: &PollRepository,
+    live_results: &LiveResultsHub,
+    poll_id: &str,
+    user_id: &str,
+    access_token: Option<&str>,
+) -> Result<EventStream, AppError> {
+    verify_live_results_access(poll_repository, poll_id, user_id, access_token).await?;
+
+    // Subscribe before reading the counts so votes cast in between aren't missed
+    let subscription = live_results.subscribe(poll_id, None);
+    poll_repository
+        .record_viewers(poll_id, subscription.viewers)
+        .await?;
+    let poll = poll_repository
+        .get_poll_results(poll_id.to_string())
+        .await?;
+
+    let snapshot = LiveUpdate::new(
+        subscription.seq,
+        poll_id.to_string(),
+        LiveEvent::Snapshot(LiveResults::from(&poll)),
+    );
+    let updates = live_results
+        .updates(subscription, poll_id)
+        .map(|update| LiveUpdate::clone(&update));
+    Ok(Box::pin(tokio_stream::once(snapshot).chain(updates)))
+}
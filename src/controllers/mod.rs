@@ -1,2 +1,15 @@
 pub mod auth_controller;
+pub mod chart_controller;
+pub mod collaborator_controller;
+pub mod comment_controller;
+pub mod guest_controller;
+pub mod live_controller;
+pub mod organisation_controller;
+pub mod password_controller;
 pub mod poll_controller;
+pub mod presentation_controller;
+pub mod qr_controller;
+pub mod question_controller;
+pub mod series_controller;
+pub mod template_controller;
+pub mod user_group_controller;
@@ -0,0 +1,231 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::{CreateOrganisationDTO, JoinOrganisationDTO, UpdateMemberRoleDTO},
+        responses::{ApiResponse, OrganisationResponseDTO, PollResponseDTO},
+    },
+    error::AppError,
+    models::organisation::OrgRole,
+    repositories::{
+        organisation_repository::OrganisationRepository, poll_repository::PollRepository,
+    },
+    utils::validation::ValidatedJson,
+};
+
+//?POST:: api/orgs
+pub async fn create_organisation(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<CreateOrganisationDTO>,
+) -> Result<Json<ApiResponse<OrganisationResponseDTO>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let organisation = organisation_repository
+        .create_organisation(payload.name, user_id.clone())
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Organisation created successfully"),
+        data: Some(organisation.to_response_dto(&user_id)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/orgs
+pub async fn get_user_organisations(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<OrganisationResponseDTO>>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let organisations = organisation_repository
+        .get_organisations_of_user(&user_id)
+        .await?
+        .iter()
+        .map(|organisation| organisation.to_response_dto(&user_id))
+        .collect();
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Organisations fetched successfully"),
+        data: Some(organisations),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/orgs/org_id
+pub async fn get_organisation_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(org_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<OrganisationResponseDTO>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let organisation = organisation_repository
+        .get_organisation_of_member(&org_id, &user_id, OrgRole::Viewer)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Organisation retrieved successfully"),
+        data: Some(organisation.to_response_dto(&user_id)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/orgs/org_id
+pub async fn delete_organisation_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(org_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    organisation_repository
+        .get_organisation_of_member(&org_id, &user_id, OrgRole::Owner)
+        .await?;
+    organisation_repository.delete_organisation(&org_id).await?;
+    poll_repository.detach_org_polls(&org_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Organisation deleted successfully"),
+        data: Some(String::from("Organisation deleted successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/orgs/join
+pub async fn join_organisation(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<JoinOrganisationDTO>,
+) -> Result<Json<ApiResponse<OrganisationResponseDTO>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let organisation = organisation_repository
+        .join_organisation(&payload.joinCode, &user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Joined organisation successfully"),
+        data: Some(organisation.to_response_dto(&user_id)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/orgs/org_id/join-code
+pub async fn rotate_organisation_join_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(org_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<OrganisationResponseDTO>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    organisation_repository
+        .get_organisation_of_member(&org_id, &user_id, OrgRole::Admin)
+        .await?;
+    let organisation = organisation_repository.rotate_join_code(&org_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Join code rotated successfully"),
+        data: Some(organisation.to_response_dto(&user_id)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?PATCH:: api/orgs/org_id/members/user_id
+pub async fn update_member_role(
+    Extension(db): Extension<Arc<Database>>,
+    Path((org_id, member_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    Json(payload): Json<UpdateMemberRoleDTO>,
+) -> Result<Json<ApiResponse<OrganisationResponseDTO>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let organisation = organisation_repository
+        .update_member_role(&org_id, &user_id, &member_id, payload.role)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Member role updated successfully"),
+        data: Some(organisation.to_response_dto(&user_id)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/orgs/org_id/members/user_id
+pub async fn remove_member(
+    Extension(db): Extension<Arc<Database>>,
+    Path((org_id, member_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    organisation_repository
+        .remove_member(&org_id, &user_id, &member_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Member removed successfully"),
+        data: Some(String::from("Member removed successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/orgs/org_id/polls
+pub async fn get_organisation_polls(
+    Extension(db): Extension<Arc<Database>>,
+    Path(org_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PollResponseDTO>>>, AppError> {
+    let organisation_repository = OrganisationRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let organisation = organisation_repository
+        .get_organisation_of_member(&org_id, &user_id, OrgRole::Viewer)
+        .await?;
+    let polls = poll_repository
+        .get_polls_of_org(&org_id, &user_id, organisation.role_of(&user_id))
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Organisation polls fetched successfully"),
+        data: Some(polls),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
@@ -0,0 +1,154 @@
+use axum::{
+    extract::{ConnectInfo, Path},
+    http::{HeaderMap, StatusCode},
+    Extension, Json,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::{net::SocketAddr, sync::Arc};
+
+use crate::{
+    config::startup::AppState,
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::PollPasswordDTO,
+        responses::{ApiResponse, PollAccessTokenDTO},
+    },
+    error::{AppError, JwtError, PollsError},
+    models::poll::PollAction,
+    repositories::poll_repository::PollRepository,
+    utils::{
+        fingerprint::client_ip,
+        jwt::{create_poll_access_token, verify_poll_access_token},
+        password::{hash_password, verify_password},
+        validation::ValidatedJson,
+    },
+};
+
+const ACCESS_TOKEN_TTL_MINUTES: i64 = 30;
+
+fn jwt_secret() -> Result<Vec<u8>, AppError> {
+    std::env::var("JWT_SECRET")
+        .map(|s| s.into_bytes())
+        .map_err(|_| AppError::JwtError(JwtError::MissingSecret))
+}
+
+/// Fails with `PasswordRequired` unless the poll has no password, the user manages its
+/// access or brings an access token issued since the password was last changed
+pub(crate) async fn verify_poll_unlocked(
+    poll_repository: &PollRepository,
+    poll_id: &str,
+    user_id: &str,
+    access_token: Option<&str>,
+) -> Result<(), AppError> {
+    let Some(changed_at) = poll_repository.get_password_lock(poll_id, user_id).await? else {
+        return Ok(());
+    };
+
+    let secret = jwt_secret()?;
+    access_token
+        .and_then(|token| verify_poll_access_token(token, &secret).ok())
+        .filter(|claims| claims.sub == poll_id && claims.iat >= changed_at)
+        .map(|_| ())
+        .ok_or(AppError::Poll(PollsError::PasswordRequired))
+}
+
+//?POST:: api/polls/poll_id/password
+pub async fn set_poll_password(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<PollPasswordDTO>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+    let password_hash = hash_password(payload.password).await?;
+    poll_repository
+        .set_password(&poll_id, Some(password_hash))
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll password set successfully"),
+        data: Some(String::from("Poll password set successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/polls/poll_id/password
+pub async fn remove_poll_password(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+    poll_repository.set_password(&poll_id, None).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll password removed successfully"),
+        data: Some(String::from("Poll password removed successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/unlock
+pub async fn unlock_poll(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    ValidatedJson(payload): ValidatedJson<PollPasswordDTO>,
+) -> Result<Json<ApiResponse<PollAccessTokenDTO>>, AppError> {
+    let poll_repository = PollRepository::new(db);
+
+    // Throttled per poll and client, so one poll being guessed doesn't lock out others and
+    // a client guessing doesn't lock out the rest of the audience. Attempts are counted up
+    // front and forgotten when the password was right.
+    let attempt_key = format!("{}:{}", poll_id, client_ip(&headers, addr));
+    app_state.unlock_attempts.attempt(&attempt_key)?;
+
+    let password_hash =
+        poll_repository
+            .get_password_hash(&poll_id)
+            .await?
+            .ok_or(AppError::Poll(PollsError::InvalidConfiguration(
+                String::from("This poll has no password"),
+            )))?;
+
+    if !verify_password(payload.password, password_hash).await? {
+        return Err(AppError::Poll(PollsError::IncorrectPollPassword));
+    }
+    app_state.unlock_attempts.reset(&attempt_key);
+
+    let ttl = chrono::Duration::minutes(ACCESS_TOKEN_TTL_MINUTES);
+    let access_token = create_poll_access_token(&poll_id, ttl, &jwt_secret()?)?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll unlocked successfully"),
+        data: Some(PollAccessTokenDTO {
+            poll_id,
+            access_token,
+            expires_at: Utc::now() + ttl,
+        }),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
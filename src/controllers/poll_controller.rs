@@ -16,20 +16,51 @@ use std::{sync::Arc, time::Duration};
 use tokio_stream::{Stream, StreamExt};
 
 use crate::{
+    config::startup::AppState,
+    controllers::password_controller::verify_poll_unlocked,
     dtos::{
         requests::{
-            CreatePollDTO, DateWithTimezone, ResultQueryParams, UpdatePollDTO, UpdatePollReq,
-            VoteQueryParam,
+            AllowUserDTO, ClonePollDTO, CreatePollDTO, DateWithTimezone, PollReactionDTO,
+            RedeemInviteDTO, ResultQueryParams, RevisionDiffQueryParams, UpdatePollDTO,
+            UpdatePollReq, VoteQueryParam,
+        },
+        responses::{
+            ApiResponse, JoinCodeResponseDTO, LiveEvent, LiveResults, LiveStreamTokenDTO,
+            LiveUpdate, PollAccessResponseDTO, PollAnalyticsDTO, PollResponseDTO,
+            PollRevisionDiffDTO, PollRevisionResponseDTO, PollVoterDTO,
         },
-        responses::{ApiResponse, PollResponseDTO},
     },
     error::{AppError, JwtError, PollsError},
-    repositories::poll_repository::{self, PollRepository},
-    utils::jwt::Claims,
+    middleware::auth::auth_token,
+    models::{
+        organisation::OrgRole,
+        poll::{PollAction, Reaction},
+    },
+    repositories::{
+        organisation_repository::OrganisationRepository,
+        poll_repository::{self, PollRepository},
+        poll_revision_repository::PollRevisionRepository,
+        user_group_repository::UserGroupRepository,
+        user_repository::UserRepository,
+    },
+    services::live_results::LiveResultsHub,
+    utils::{
+        jwt::{create_live_stream_token, verify_live_stream_token, Claims},
+        poll_access::PollAccessToken,
+        validation::ValidatedJson,
+    },
 };
 
+const STREAM_TOKEN_TTL_MINUTES: i64 = 10;
+
+fn jwt_secret() -> Result<Vec<u8>, AppError> {
+    std::env::var("JWT_SECRET")
+        .map(|s| s.into_bytes())
+        .map_err(|_| AppError::JwtError(JwtError::MissingSecret))
+}
+
 // Helper function to extract user_id from JWT
-async fn get_user_id_from_token(token: &str) -> Result<String, AppError> {
+pub(crate) async fn get_user_id_from_token(token: &str) -> Result<String, AppError> {
     let jwt_secret = std::env::var("JWT_SECRET")
         .map(|s| s.into_bytes())
         .map_err(|_| AppError::JwtError(JwtError::MissingSecret))?;
@@ -44,12 +75,42 @@ async fn get_user_id_from_token(token: &str) -> Result<String, AppError> {
     Ok(token_data.claims.sub)
 }
 
+// Helper function to extract user_id from a live stream token issued for this poll
+fn get_user_id_from_stream_token(token: &str, poll_id: &str) -> Result<String, AppError> {
+    let claims = verify_live_stream_token(token, &jwt_secret()?)?;
+
+    if claims.poll != poll_id {
+        return Err(AppError::JwtError(JwtError::InvalidToken));
+    }
+
+    Ok(claims.sub)
+}
+
+// Helper function to check the user may create polls in the organisation and for the groups
+async fn verify_poll_audience(
+    db: &Arc<Database>,
+    org_id: Option<&str>,
+    target_groups: &[String],
+    user_id: &str,
+) -> Result<(), AppError> {
+    if let Some(org_id) = org_id {
+        OrganisationRepository::new(db.clone())
+            .get_organisation_of_member(org_id, user_id, OrgRole::Editor)
+            .await?;
+    }
+    UserGroupRepository::new(db.clone())
+        .verify_groups_of_owner(target_groups, user_id)
+        .await
+}
+
 //*GET:: api/polls
 pub async fn get_all_polls(
     Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Json<ApiResponse<Vec<PollResponseDTO>>>, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
-    match poll_repository.get_all_polls().await {
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+    match poll_repository.get_all_polls(&user_id).await {
         Ok(polls) => Ok(Json(ApiResponse {
             status: StatusCode::OK.as_u16() as i32,
             message: String::from("All posts fetched successfully"),
@@ -87,7 +148,7 @@ pub async fn manage_all_polls(
 pub async fn create_new_poll(
     Extension(db): Extension<Arc<Database>>,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
-    axum::Json(mut payload): axum::Json<CreatePollDTO>,
+    ValidatedJson(mut payload): ValidatedJson<CreatePollDTO>,
 ) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
     // Override createdBy with the authenticated user's ID
     println!("Create poll req body:: {:#?}", payload);
@@ -95,15 +156,28 @@ pub async fn create_new_poll(
     println!("Poll being created by:: {:#?}", user_id);
     payload.createdBy = user_id;
 
-    let poll_repository = poll_repository::PollRepository::new(db);
+    verify_poll_audience(
+        &db,
+        payload.orgId.as_deref(),
+        &payload.targetGroups,
+        &payload.createdBy,
+    )
+    .await?;
+
+    let poll_repository = poll_repository::PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db);
     match poll_repository.create_poll(payload).await {
-        Ok(poll) => Ok(Json(ApiResponse {
-            status: http::StatusCode::CREATED.as_u16() as i32,
-            message: String::from("Poll created successfully"),
-            data: Some(poll),
-            timestamp: Utc::now(),
-            error: None,
-        })),
+        Ok(poll) => {
+            revision_repository.record_creation(&poll).await?;
+
+            Ok(Json(ApiResponse {
+                status: http::StatusCode::CREATED.as_u16() as i32,
+                message: String::from("Poll created successfully"),
+                data: Some(poll),
+                timestamp: Utc::now(),
+                error: None,
+            }))
+        }
         Err(e) => Err(e),
     }
 }
@@ -112,12 +186,26 @@ pub async fn create_new_poll(
 pub async fn get_poll_by_id(
     Extension(db): Extension<Arc<Database>>,
     Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_access(&poll_id, &user_id)
+        .await?;
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
     let poll = poll_repository
-        .get_poll_by_id(poll_id)
-        .await?
-        .ok_or(AppError::Poll(PollsError::PollNotFound))?;
+        .get_poll_for_user(&poll_id, &user_id)
+        .await?;
 
     Ok(Json(ApiResponse {
         status: http::StatusCode::OK.as_u16() as i32,
@@ -131,15 +219,33 @@ pub async fn get_poll_by_id(
 //?PATCH:: api/polls/poll_id
 pub async fn update_poll_by_id(
     Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
     Path(poll_id): Path<String>,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
-    axum::Json(payload): axum::Json<UpdatePollDTO>,
+    ValidatedJson(payload): ValidatedJson<UpdatePollDTO>,
 ) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db.clone());
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::Edit)
+        .await?;
+    if let Some(target_groups) = &payload.targetGroups {
+        group_repository
+            .verify_groups_of_owner(target_groups, &user_id)
+            .await?;
+    }
+
+    let poll_before = poll_repository.get_poll_results(poll_id.clone()).await?;
+
     // Adjust dates to preserve local time
     // Parse the ISO dates directly - they already contain timezone information
     let start_date = DateTime::parse_from_rfc3339(&payload.startDate)
         .map_err(|_| {
-            AppError::Poll(PollsError::UpdateFailed(
+            AppError::Poll(PollsError::InvalidPollDates(
                 "Invalid date format for start date".to_string(),
             ))
         })?
@@ -147,26 +253,40 @@ pub async fn update_poll_by_id(
 
     let end_date = DateTime::parse_from_rfc3339(&payload.endDate)
         .map_err(|_| {
-            AppError::Poll(PollsError::UpdateFailed(
-                "Invalid date format for start date".to_string(),
+            AppError::Poll(PollsError::InvalidPollDates(
+                "Invalid date format for end date".to_string(),
             ))
         })?
         .with_timezone(&Utc);
 
     // Use adjusted dates in your update logic
-    let poll_repository = poll_repository::PollRepository::new(db);
-    let update_result = poll_repository
+    let removed_votes = poll_repository
         .update_poll(
-            poll_id,
+            poll_id.clone(),
             UpdatePollReq {
                 name: payload.name,
                 isMulti: payload.isMulti,
                 startDate: start_date,
                 endDate: end_date,
+                options: payload.options,
+                removedVotes: payload.removedVotes,
+                isPrivate: payload.isPrivate,
+                targetGroups: payload.targetGroups,
+                allowGuests: payload.allowGuests,
+                guestFingerprinting: payload.guestFingerprinting,
+                resultsVisibility: payload.resultsVisibility,
+                shuffleOptions: payload.shuffleOptions,
             },
         )
         .await?;
 
+    let poll_after = poll_repository.get_poll_results(poll_id).await?;
+    // Options may have been renamed, added or removed
+    app_state.live_results.publish(&poll_after);
+    revision_repository
+        .record_edit(&poll_before, &poll_after, removed_votes.as_ref(), &user_id)
+        .await?;
+
     Ok(Json(ApiResponse {
         status: http::StatusCode::OK.as_u16() as i32,
         message: "Poll updated successfully".to_string(),
@@ -176,19 +296,342 @@ pub async fn update_poll_by_id(
     }))
 }
 
+//?POST:: api/polls/poll_id/clone
+pub async fn clone_poll_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<ClonePollDTO>,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db.clone());
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::Edit)
+        .await?;
+    // The copy belongs to the user, who must be allowed to place it where the original is
+    let source = poll_repository
+        .get_poll_by_id(poll_id.clone())
+        .await?
+        .ok_or(AppError::Poll(PollsError::PollNotFound))?;
+    verify_poll_audience(
+        &db,
+        source.org_id.as_deref(),
+        &source.target_groups,
+        &user_id,
+    )
+    .await?;
+
+    let poll = poll_repository
+        .clone_poll(poll_id, payload, user_id)
+        .await?;
+    revision_repository.record_creation(&poll).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Poll cloned successfully"),
+        data: Some(poll),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/access/redeem
+pub async fn redeem_invite_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<RedeemInviteDTO>,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .redeem_invite_code(&poll_id, &payload.inviteCode, &user_id)
+        .await?;
+    // The invite grants access, the password still has to be known
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+    let poll = poll_repository
+        .get_poll_for_user(&poll_id, &user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Invite code redeemed successfully"),
+        data: Some(poll),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/access
+pub async fn get_poll_access(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollAccessResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+
+    let access = poll_repository.get_poll_access(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll access retrieved successfully"),
+        data: Some(access),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/access/users
+pub async fn allow_poll_user(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<AllowUserDTO>,
+) -> Result<Json<ApiResponse<PollAccessResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+
+    poll_repository
+        .allow_user(&poll_id, &payload.userId)
+        .await?;
+    let access = poll_repository.get_poll_access(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("User added to poll successfully"),
+        data: Some(access),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/polls/poll_id/access/users/user_id
+pub async fn revoke_poll_user(
+    Extension(db): Extension<Arc<Database>>,
+    Path((poll_id, revoked_user_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollAccessResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+
+    poll_repository
+        .revoke_user(&poll_id, &revoked_user_id)
+        .await?;
+    let access = poll_repository.get_poll_access(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("User removed from poll successfully"),
+        data: Some(access),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/access/rotate
+pub async fn rotate_poll_invite_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollAccessResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+
+    poll_repository.rotate_invite_code(&poll_id).await?;
+    let access = poll_repository.get_poll_access(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Invite code rotated successfully"),
+        data: Some(access),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/join/join_code
+pub async fn get_poll_by_join_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(join_code): Path<String>,
+) -> Result<Json<ApiResponse<JoinCodeResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let poll = poll_repository.find_by_join_code(&join_code).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Join code resolved successfully"),
+        data: Some(poll),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/polls/poll_id/join-code
+pub async fn regenerate_join_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<JoinCodeResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ManageAccess)
+        .await?;
+
+    let join_code = poll_repository.regenerate_join_code(&poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Join code regenerated successfully"),
+        data: Some(join_code),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/revisions
+pub async fn get_poll_revisions(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PollRevisionResponseDTO>>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_access(&poll_id, &user_id)
+        .await?;
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+    let revisions = revision_repository.get_revisions(poll_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll revisions retrieved successfully"),
+        data: Some(revisions),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/revisions/diff
+pub async fn get_poll_revision_diff(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    Query(query): Query<RevisionDiffQueryParams>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollRevisionDiffDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_access(&poll_id, &user_id)
+        .await?;
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+    let diff = revision_repository
+        .diff_revisions(poll_id, query.from, query.to)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll revision diff retrieved successfully"),
+        data: Some(diff),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+/// Casts a vote after checking the user may access the poll, the poll as the user sees it
+/// afterwards. Shared by the HTTP and WebSocket vote paths.
+pub(crate) async fn vote_on_poll(
+    poll_repository: &PollRepository,
+    live_results: &LiveResultsHub,
+    poll_id: &str,
+    option_id: String,
+    user_id: &str,
+    access_token: Option<&str>,
+) -> Result<PollResponseDTO, AppError> {
+    poll_repository.verify_poll_access(poll_id, user_id).await?;
+    verify_poll_unlocked(poll_repository, poll_id, user_id, access_token).await?;
+
+    let poll = poll_repository
+        .cast_vote(poll_id.to_string(), option_id, user_id.to_string())
+        .await?;
+    live_results.publish(&poll);
+
+    poll_repository.get_poll_for_user(poll_id, user_id).await
+}
+
 //*GET:: api/polls/poll_id/vote
 pub async fn cast_vote(
     Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
     Path(poll_id): Path<String>,
     Query(query): Query<VoteQueryParam>,
+    PollAccessToken(access_token): PollAccessToken,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
     let user_id = get_user_id_from_token(authorization.token()).await?;
 
-    let updated_poll = poll_repository
-        .cast_vote(poll_id, query.optionId, user_id)
-        .await?;
+    let updated_poll = vote_on_poll(
+        &poll_repository,
+        &app_state.live_results,
+        &poll_id,
+        query.optionId,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
 
     Ok(Json(ApiResponse {
         status: http::StatusCode::OK.as_u16() as i32,
@@ -202,11 +645,23 @@ pub async fn cast_vote(
 pub async fn can_user_vote(
     Extension(db): Extension<Arc<Database>>,
     Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Json<ApiResponse<bool>>, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
     let user_id = get_user_id_from_token(authorization.token()).await?;
 
+    poll_repository
+        .verify_poll_access(&poll_id, &user_id)
+        .await?;
+    verify_poll_unlocked(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
     let can_vote = poll_repository.can_vote(user_id, poll_id).await?;
 
     Ok(Json(ApiResponse {
@@ -226,21 +681,20 @@ pub async fn can_user_vote(
 //*GET:: api/polls/poll_id/close
 pub async fn close_poll_by_id(
     Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
     Path(poll_id): Path<String>,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
     let user_id = get_user_id_from_token(authorization.token()).await?;
 
-    // Verify ownership
-    if !poll_repository
-        .verify_poll_owner(&poll_id, &user_id)
-        .await?
-    {
-        return Err(AppError::Poll(PollsError::Unauthorized));
-    }
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::Close)
+        .await?;
 
     let updated_poll = poll_repository.close_poll(poll_id).await?;
+    app_state.live_results.publish(&updated_poll);
 
     Ok(Json(ApiResponse {
         status: http::StatusCode::OK.as_u16() as i32,
@@ -251,24 +705,74 @@ pub async fn close_poll_by_id(
     }))
 }
 
+//?POST:: api/polls/poll_id/pause
+pub async fn pause_poll_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    set_poll_paused(db, app_state, poll_id, authorization, true).await
+}
+
+//?POST:: api/polls/poll_id/resume
+pub async fn resume_poll_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    set_poll_paused(db, app_state, poll_id, authorization, false).await
+}
+
+async fn set_poll_paused(
+    db: Arc<Database>,
+    app_state: AppState,
+    poll_id: String,
+    authorization: Authorization<Bearer>,
+    paused: bool,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Pausing is part of the poll's lifecycle, like closing it
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::Close)
+        .await?;
+
+    let updated_poll = poll_repository.set_paused(poll_id, paused).await?;
+    app_state.live_results.publish(&updated_poll);
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from(if paused {
+            "Poll paused successfully"
+        } else {
+            "Poll resumed successfully"
+        }),
+        data: Some(updated_poll),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
 //*GET:: api/polls/poll_id/reset
 pub async fn reset_poll_by_id(
     Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
     Path(poll_id): Path<String>,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
     let user_id = get_user_id_from_token(authorization.token()).await?;
 
-    // Verify ownership
-    if !poll_repository
-        .verify_poll_owner(&poll_id, &user_id)
-        .await?
-    {
-        return Err(AppError::Poll(PollsError::Unauthorized));
-    }
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::Reset)
+        .await?;
 
     let updated_poll = poll_repository.reset_poll(poll_id).await?;
+    app_state.live_results.publish(&updated_poll);
 
     Ok(Json(ApiResponse {
         status: http::StatusCode::OK.as_u16() as i32,
@@ -279,11 +783,79 @@ pub async fn reset_poll_by_id(
     }))
 }
 
+//*GET:: api/polls/poll_id/voters
+pub async fn get_poll_voters(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PollVoterDTO>>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db.clone());
+    let user_repository = UserRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify permission
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ViewVoters)
+        .await?;
+
+    let voter_ids = poll_repository.get_voters(&poll_id).await?;
+    let users = user_repository
+        .get_users_by_ids(&voter_ids)
+        .await
+        .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+    let voters = voter_ids
+        .into_iter()
+        .map(|voter_id| PollVoterDTO {
+            username: users
+                .iter()
+                .find(|user| user.user_id == voter_id)
+                .map(|user| user.username.clone()),
+            user_id: voter_id,
+        })
+        .collect();
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll voters retrieved successfully"),
+        data: Some(voters),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/polls/poll_id/analytics
+pub async fn get_poll_analytics(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollAnalyticsDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    poll_repository
+        .verify_poll_permission(&poll_id, &user_id, PollAction::ViewResults)
+        .await?;
+    let analytics = poll_repository
+        .get_analytics(&poll_id, app_state.live_results.viewers(&poll_id))
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll analytics retrieved successfully"),
+        data: Some(analytics),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
 //*GET:: api/polls/poll_id/results
 pub async fn get_poll_result(
     Extension(db): Extension<Arc<Database>>,
     Path(poll_id): Path<String>,
     Query(filters): Query<ResultQueryParams>,
+    PollAccessToken(access_token): PollAccessToken,
     TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
 ) -> Result<Response, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
@@ -295,64 +867,215 @@ pub async fn get_poll_result(
     } else {
         // Validate the authentication token
         let user_id = get_user_id_from_token(authorization.token()).await?;
+        poll_repository
+            .verify_poll_access(&poll_id, &user_id)
+            .await?;
+        verify_poll_unlocked(
+            &poll_repository,
+            &poll_id,
+            &user_id,
+            access_token.as_deref(),
+        )
+        .await?;
         Ok(get_poll_result_by_id(poll_repository, poll_id, user_id)
             .await?
             .into_response())
     }
 }
 
+/// Fails unless the user may watch the live results of the poll
+pub(crate) async fn verify_live_results_access(
+    poll_repository: &PollRepository,
+    poll_id: &str,
+    user_id: &str,
+    access_token: Option<&str>,
+) -> Result<(), AppError> {
+    poll_repository.verify_poll_access(poll_id, user_id).await?;
+    verify_poll_unlocked(poll_repository, poll_id, user_id, access_token).await?;
+    if !poll_repository.can_view_results(poll_id, user_id).await? {
+        return Err(AppError::Poll(PollsError::ResultsHidden));
+    }
+
+    Ok(())
+}
+
+/// Passes a reaction on to the live viewers of the poll. Shared by the HTTP and WebSocket
+/// reaction paths.
+pub(crate) async fn react_to_poll(
+    poll_repository: &PollRepository,
+    app_state: &AppState,
+    poll_id: &str,
+    reaction: Reaction,
+    user_id: &str,
+    access_token: Option<&str>,
+) -> Result<(), AppError> {
+    app_state.reaction_limiter.attempt(user_id)?;
+    verify_live_results_access(poll_repository, poll_id, user_id, access_token).await?;
+
+    let poll = poll_repository
+        .get_poll_results(poll_id.to_string())
+        .await?;
+    if poll.is_closed {
+        return Err(AppError::Poll(PollsError::PollClosed));
+    }
+
+    app_state.live_results.react(poll_id, reaction);
+    Ok(())
+}
+
+//?POST:: api/polls/poll_id/reactions
+pub async fn send_poll_reaction(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    Json(payload): Json<PollReactionDTO>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    react_to_poll(
+        &poll_repository,
+        &app_state,
+        &poll_id,
+        payload.reaction,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::OK.as_u16() as i32,
+        message: String::from("Reaction sent successfully"),
+        data: Some(String::from("Reaction sent successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
 //*GET:: api/polls/poll_id/results/live
 pub async fn get_poll_live_results(
     Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
     Path(poll_id): Path<String>,
+    Query(filters): Query<ResultQueryParams>,
+    headers: http::HeaderMap,
 ) -> Result<Response, AppError> {
     let poll_repository = poll_repository::PollRepository::new(db);
 
-    // No authentication required for live results
-    // We'll pass an empty string as user_id since we're not using it in the stream
-    Ok(start_sse(poll_repository, poll_id, String::new())
-        .await
-        .into_response())
+    // No authentication required for live results of public polls. EventSource can't send
+    // headers, so browsers watching private ones bring the session cookie or a stream token,
+    // which unlike the session token is fine to end up in URLs and logs.
+    let user_id = match (filters.streamToken, auth_token(&headers)) {
+        (Some(token), _) => get_user_id_from_stream_token(&token, &poll_id)?,
+        (None, Some(token)) => get_user_id_from_token(&token).await?,
+        (None, None) => String::new(),
+    };
+    verify_live_results_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        filters.accessToken.as_deref(),
+    )
+    .await?;
+
+    let last_event_id = headers
+        .get("last-event-id")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<u64>().ok())
+        .or(filters.lastEventId);
+
+    Ok(start_sse(
+        app_state.live_results,
+        poll_repository,
+        poll_id,
+        last_event_id,
+    )
+    .await?
+    .into_response())
+}
+
+//?POST:: api/polls/poll_id/results/live/token
+pub async fn issue_live_stream_token(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    PollAccessToken(access_token): PollAccessToken,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<LiveStreamTokenDTO>>, AppError> {
+    let poll_repository = poll_repository::PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    verify_live_results_access(
+        &poll_repository,
+        &poll_id,
+        &user_id,
+        access_token.as_deref(),
+    )
+    .await?;
+
+    let ttl = chrono::Duration::minutes(STREAM_TOKEN_TTL_MINUTES);
+    let stream_token = create_live_stream_token(&user_id, &poll_id, ttl, &jwt_secret()?)?;
+
+    Ok(Json(ApiResponse {
+        status: http::StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Live stream token issued successfully"),
+        data: Some(LiveStreamTokenDTO {
+            poll_id,
+            stream_token,
+            expires_at: Utc::now() + ttl,
+        }),
+        timestamp: Utc::now(),
+        error: None,
+    }))
 }
 
 pub async fn start_sse(
+    live_results: LiveResultsHub,
     poll_repository: PollRepository,
     poll_id: String,
-    user_id: String,
-) -> Sse<impl Stream<Item = Result<Event, AppError>>> {
-    // Create a stream that fetches poll results every second
-    let stream =
-        tokio_stream::wrappers::IntervalStream::new(tokio::time::interval(Duration::from_secs(1)))
-            .then(move |_| {
-                let poll_repo = poll_repository.clone();
-                let poll_id = poll_id.clone();
-
-                async move {
-                    match poll_repo.get_poll_results(poll_id).await {
-                        Ok(poll) => {
-                            let options_json =
-                                serde_json::to_string(&poll.options).unwrap_or_default();
-
-                            Ok(Event::default().data(options_json).event("poll-update"))
-                        }
-                        Err(_) => Ok(Event::default()
-                            .data("Error fetching poll results")
-                            .event("error")),
-                    }
-                }
-            })
-            .then(|future: Result<Event, AppError>| async move {
-                match future {
-                    Ok(event) => Ok(event),
-                    Err(e) => Err(e),
-                }
-            });
-
-    Sse::new(stream).keep_alive(
+    last_event_id: Option<u64>,
+) -> Result<Sse<impl Stream<Item = Result<Event, AppError>>>, AppError> {
+    // Subscribe before reading the counts so votes cast in between aren't missed. Events
+    // are pushed by the mutations, every viewer of the poll shares them.
+    let mut subscription = live_results.subscribe(&poll_id, last_event_id);
+    poll_repository
+        .record_viewers(&poll_id, subscription.viewers)
+        .await?;
+
+    let first_events = match subscription.missed.take() {
+        // Resuming, replay what the viewer missed
+        Some(missed) => missed.iter().map(|update| live_event(update)).collect(),
+        // New viewers, and those whose missed events aren't kept anymore, start from a snapshot
+        None => {
+            let poll = poll_repository.get_poll_results(poll_id.clone()).await?;
+            let snapshot = LiveUpdate::new(
+                subscription.seq,
+                poll_id.clone(),
+                LiveEvent::Snapshot(LiveResults::from(&poll)),
+            );
+            vec![live_event(&snapshot)]
+        }
+    };
+
+    let updates = live_results
+        .updates(subscription, &poll_id)
+        .map(|update| live_event(&update));
+    let stream = tokio_stream::iter(first_events).chain(updates).map(Ok);
+
+    Ok(Sse::new(stream).keep_alive(
         axum::response::sse::KeepAlive::new()
             .interval(Duration::from_secs(1))
             .text("keep-alive-text"),
-    )
+    ))
+}
+
+// The sequence number is the event id, browsers send it back as `Last-Event-ID`
+fn live_event(update: &LiveUpdate) -> Event {
+    Event::default()
+        .id(update.seq.to_string())
+        .event(update.event_type())
+        .data(serde_json::to_string(update).unwrap_or_default())
 }
 
 pub async fn get_poll_result_by_id(
@@ -360,6 +1083,10 @@ pub async fn get_poll_result_by_id(
     poll_id: String,
     user_id: String,
 ) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    if !poll_repository.can_view_results(&poll_id, &user_id).await? {
+        return Err(AppError::Poll(PollsError::ResultsHidden));
+    }
+
     let poll = poll_repository.get_poll_results(poll_id).await?;
 
     Ok(Json(ApiResponse {
@@ -0,0 +1,414 @@
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{sse::Event, Sse},
+    Extension, Json,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::{sync::Arc, time::Duration};
+use tokio_stream::{Stream, StreamExt};
+
+use crate::{
+    config::startup::AppState,
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::CreatePresentationDTO,
+        responses::{ApiResponse, PresentationResponseDTO, PresentationStateDTO},
+    },
+    error::{AppError, PollsError},
+    models::{
+        poll::PollAction,
+        presentation::{Presentation, PresentationPhase},
+    },
+    repositories::{
+        poll_repository::PollRepository, presentation_repository::PresentationRepository,
+        question_repository::QuestionRepository,
+    },
+    services::live_results::LiveResultsHub,
+    utils::validation::ValidatedJson,
+};
+
+enum PresenterAction {
+    Start,
+    Next,
+    Previous,
+    Lock,
+    Reveal,
+    End,
+}
+
+// Helper function to fetch a presentation only its presenter may drive
+async fn get_owned_presentation(
+    presentation_repository: &PresentationRepository,
+    presentation_id: String,
+    user_id: &str,
+) -> Result<Presentation, AppError> {
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id)
+        .await?;
+    if presentation.createdBy != user_id {
+        return Err(AppError::Poll(PollsError::Unauthorized));
+    }
+    Ok(presentation)
+}
+
+//?POST:: api/presentations
+pub async fn create_presentation(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<CreatePresentationDTO>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Presenting pauses and resumes the polls, so the presenter must be able to edit them
+    for poll_id in &payload.pollIds {
+        poll_repository
+            .verify_poll_permission(poll_id, &user_id, PollAction::Edit)
+            .await?;
+    }
+    let presentation = presentation_repository
+        .create_presentation(payload, user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Presentation created successfully"),
+        data: Some(presentation),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/presentations
+pub async fn get_user_presentations(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PresentationResponseDTO>>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let presentations = presentation_repository
+        .get_presentations_of_user(user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Presentations fetched successfully"),
+        data: Some(presentations),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/presentations/presentation_id
+pub async fn get_presentation_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let presentation =
+        get_owned_presentation(&presentation_repository, presentation_id, &user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Presentation retrieved successfully"),
+        data: Some(presentation.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/presentations/presentation_id
+pub async fn delete_presentation(
+    Extension(db): Extension<Arc<Database>>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let question_repository = QuestionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    get_owned_presentation(&presentation_repository, presentation_id.clone(), &user_id).await?;
+    question_repository
+        .delete_questions_of_presentation(&presentation_id)
+        .await?;
+    presentation_repository
+        .delete_presentation(presentation_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Presentation deleted successfully"),
+        data: Some(String::from("Presentation deleted successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/presentations/presentation_id/start
+pub async fn start_presentation(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    drive_presentation(
+        db,
+        app_state,
+        presentation_id,
+        authorization.token(),
+        PresenterAction::Start,
+    )
+    .await
+}
+
+//?POST:: api/presentations/presentation_id/next
+pub async fn next_presentation_poll(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    drive_presentation(
+        db,
+        app_state,
+        presentation_id,
+        authorization.token(),
+        PresenterAction::Next,
+    )
+    .await
+}
+
+//?POST:: api/presentations/presentation_id/previous
+pub async fn previous_presentation_poll(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    drive_presentation(
+        db,
+        app_state,
+        presentation_id,
+        authorization.token(),
+        PresenterAction::Previous,
+    )
+    .await
+}
+
+//?POST:: api/presentations/presentation_id/lock
+pub async fn lock_presentation_poll(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    drive_presentation(
+        db,
+        app_state,
+        presentation_id,
+        authorization.token(),
+        PresenterAction::Lock,
+    )
+    .await
+}
+
+//?POST:: api/presentations/presentation_id/reveal
+pub async fn reveal_presentation_poll(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    drive_presentation(
+        db,
+        app_state,
+        presentation_id,
+        authorization.token(),
+        PresenterAction::Reveal,
+    )
+    .await
+}
+
+//?POST:: api/presentations/presentation_id/end
+pub async fn end_presentation(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    drive_presentation(
+        db,
+        app_state,
+        presentation_id,
+        authorization.token(),
+        PresenterAction::End,
+    )
+    .await
+}
+
+// Moves the cursor, pauses or resumes the polls involved and tells the audience
+async fn drive_presentation(
+    db: Arc<Database>,
+    app_state: AppState,
+    presentation_id: String,
+    token: &str,
+    action: PresenterAction,
+) -> Result<Json<ApiResponse<PresentationResponseDTO>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(token).await?;
+
+    let before =
+        get_owned_presentation(&presentation_repository, presentation_id, &user_id).await?;
+    let last = before.pollIds.len() as i32 - 1;
+
+    // Rights on the polls may have been taken away since the presentation was created
+    poll_repository
+        .verify_polls_permission(&before.pollIds, &user_id, PollAction::Edit)
+        .await?;
+
+    let starts = matches!(action, PresenterAction::Start);
+    let (position, phase) = match action {
+        PresenterAction::Start => (0, PresentationPhase::Voting),
+        _ if !before.phase.is_running() => {
+            return Err(AppError::Poll(PollsError::PresentationNotRunning))
+        }
+        // Moving on from the last poll ends the presentation
+        PresenterAction::Next if before.position >= last => {
+            (before.position, PresentationPhase::Ended)
+        }
+        PresenterAction::Next => (before.position + 1, PresentationPhase::Voting),
+        PresenterAction::Previous => ((before.position - 1).max(0), PresentationPhase::Voting),
+        PresenterAction::Lock => (before.position, PresentationPhase::Locked),
+        PresenterAction::Reveal => (before.position, PresentationPhase::Revealed),
+        PresenterAction::End => (before.position, PresentationPhase::Ended),
+    };
+
+    let after = presentation_repository
+        .set_cursor(&before.presentationId, position, phase)
+        .await?;
+
+    // Only the poll on screen takes votes, and only while the audience is voting
+    if starts || before.position != after.position {
+        let others: Vec<String> = after
+            .pollIds
+            .iter()
+            .filter(|poll_id| Some(poll_id.as_str()) != after.current_poll_id())
+            .cloned()
+            .collect();
+        for poll in poll_repository.pause_polls(&others).await? {
+            app_state.live_results.publish(&poll);
+        }
+    }
+    if before.phase.is_running() || after.phase.is_running() {
+        if let Some(poll_id) = after.current_poll_id() {
+            let paused = after.phase != PresentationPhase::Voting;
+            set_poll_paused(&poll_repository, &app_state.live_results, poll_id, paused).await?;
+        }
+    }
+
+    app_state.presentations.states.publish(
+        &after.presentationId,
+        presentation_state(&poll_repository, &after).await?,
+    );
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Presentation updated successfully"),
+        data: Some(after.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+async fn set_poll_paused(
+    poll_repository: &PollRepository,
+    live_results: &LiveResultsHub,
+    poll_id: &str,
+    paused: bool,
+) -> Result<(), AppError> {
+    match poll_repository
+        .set_paused(poll_id.to_string(), paused)
+        .await
+    {
+        Ok(poll) => live_results.publish(&poll),
+        // Polls deleted since the presentation was created are skipped
+        Err(AppError::Poll(PollsError::PollNotFound)) => {}
+        Err(e) => return Err(e),
+    }
+    Ok(())
+}
+
+// What the audience sees, the current poll's votes stay hidden until revealed. The stream is
+// anonymous, so polls restricted to some users aren't shown at all.
+async fn presentation_state(
+    poll_repository: &PollRepository,
+    presentation: &Presentation,
+) -> Result<PresentationStateDTO, AppError> {
+    let poll = match presentation.current_poll_id() {
+        Some(poll_id) if presentation.phase.is_running() => {
+            poll_repository.get_poll_for_audience(poll_id).await?
+        }
+        _ => None,
+    };
+
+    Ok(PresentationStateDTO {
+        presentation_id: presentation.presentationId.clone(),
+        title: presentation.title.clone(),
+        phase: presentation.phase,
+        position: presentation.position,
+        poll_count: presentation.pollIds.len(),
+        poll: poll.map(|poll| {
+            if presentation.phase == PresentationPhase::Revealed {
+                poll
+            } else {
+                poll.without_results()
+            }
+        }),
+    })
+}
+
+//*GET:: api/presentations/presentation_id/stream
+pub async fn get_presentation_stream(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, AppError>>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db);
+
+    // No authentication required, the audience joins with the presentation's link.
+    // Subscribe before reading the state so presenter actions in between aren't missed.
+    let states = app_state.presentations.states.subscribe(&presentation_id);
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id)
+        .await?;
+    let state = presentation_state(&poll_repository, &presentation).await?;
+
+    let stream = tokio_stream::once(Arc::new(state))
+        .chain(states)
+        .map(|state| Ok(state_event(&state)));
+
+    Ok(Sse::new(stream).keep_alive(
+        axum::response::sse::KeepAlive::new()
+            .interval(Duration::from_secs(1))
+            .text("keep-alive-text"),
+    ))
+}
+
+fn state_event(state: &PresentationStateDTO) -> Event {
+    Event::default()
+        .event("state")
+        .data(serde_json::to_string(state).unwrap_or_default())
+}
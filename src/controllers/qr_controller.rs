@@ -0,0 +1,103 @@
+use axum::{
+    extract::Path,
+    http::{
+        header::{CACHE_CONTROL, CONTENT_TYPE},
+        HeaderMap,
+    },
+    response::{IntoResponse, Response},
+    Extension,
+};
+use mongodb::Database;
+use qrcode::EcLevel;
+use std::sync::Arc;
+
+use crate::{
+    config::frontend::{join_link, poll_link},
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::requests::{ImageFormat, QrErrorCorrection, QrQueryParams},
+    error::{AppError, JwtError},
+    middleware::auth::auth_token,
+    repositories::poll_repository::PollRepository,
+    utils::{
+        qr::{render_png, render_svg},
+        validation::ValidatedQuery,
+    },
+};
+
+// Helper function to render a link in the requested format
+async fn qr_response(
+    link: String,
+    query: QrQueryParams,
+    cache_control: &'static str,
+) -> Result<Response, AppError> {
+    let size = query.size.unwrap_or(512);
+    let ec_level = match query.ecc.unwrap_or_default() {
+        QrErrorCorrection::L => EcLevel::L,
+        QrErrorCorrection::M => EcLevel::M,
+        QrErrorCorrection::Q => EcLevel::Q,
+        QrErrorCorrection::H => EcLevel::H,
+    };
+
+    let response = match query.format.unwrap_or_default() {
+        ImageFormat::Svg => (
+            [
+                (CONTENT_TYPE, "image/svg+xml"),
+                (CACHE_CONTROL, cache_control),
+            ],
+            render_svg(&link, size, ec_level)?,
+        )
+            .into_response(),
+        ImageFormat::Png => {
+            // Rasterising is CPU bound, so it runs off the async workers
+            let png = tokio::task::spawn_blocking(move || render_png(&link, size, ec_level))
+                .await
+                .map_err(|_| AppError::Unknown)??;
+            (
+                [(CONTENT_TYPE, "image/png"), (CACHE_CONTROL, cache_control)],
+                png,
+            )
+                .into_response()
+        }
+    };
+
+    Ok(response)
+}
+
+//*GET:: api/polls/poll_id/qr
+pub async fn get_poll_qr_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(poll_id): Path<String>,
+    headers: HeaderMap,
+    ValidatedQuery(query): ValidatedQuery<QrQueryParams>,
+) -> Result<Response, AppError> {
+    let poll_repository = PollRepository::new(db);
+
+    // Public polls are anyone's to share, the others only of those who can open them
+    if poll_repository
+        .get_poll_for_audience(&poll_id)
+        .await?
+        .is_some()
+    {
+        return qr_response(poll_link(&poll_id), query, "public, max-age=300").await;
+    }
+
+    let token = auth_token(&headers).ok_or(AppError::JwtError(JwtError::InvalidToken))?;
+    let user_id = get_user_id_from_token(&token).await?;
+    poll_repository
+        .verify_poll_access(&poll_id, &user_id)
+        .await?;
+
+    qr_response(poll_link(&poll_id), query, "private, max-age=300").await
+}
+
+//*GET:: api/polls/join/join_code/qr
+pub async fn get_join_code_qr_code(
+    Extension(db): Extension<Arc<Database>>,
+    Path(join_code): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<QrQueryParams>,
+) -> Result<Response, AppError> {
+    let poll_repository = PollRepository::new(db);
+    let poll = poll_repository.find_by_join_code(&join_code).await?;
+
+    qr_response(join_link(&poll.join_code), query, "public, max-age=300").await
+}
@@ -0,0 +1,273 @@
+use axum::{
+    extract::Path,
+    http::StatusCode,
+    response::{sse::Event, Sse},
+    Extension, Json,
+};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::{sync::Arc, time::Duration};
+use tokio_stream::{Stream, StreamExt};
+
+use crate::{
+    config::startup::AppState,
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::{AskQuestionDTO, ModerateQuestionDTO},
+        responses::{ApiResponse, QuestionBoardDTO, QuestionResponseDTO},
+    },
+    error::{AppError, PollsError},
+    repositories::{
+        presentation_repository::PresentationRepository, question_repository::QuestionRepository,
+    },
+    services::presentation_hub::PresentationHub,
+    utils::{validation::ValidatedJson, votes::has_voted},
+};
+
+// Helper function to push the re-ranked questions to the audience
+async fn publish_board(
+    question_repository: &QuestionRepository,
+    presentations: &PresentationHub,
+    presentation_id: &str,
+) -> Result<(), AppError> {
+    let board = question_board(question_repository, presentation_id).await?;
+    presentations.questions.publish(presentation_id, board);
+    Ok(())
+}
+
+// Questions the whole audience sees, without their authors
+async fn question_board(
+    question_repository: &QuestionRepository,
+    presentation_id: &str,
+) -> Result<QuestionBoardDTO, AppError> {
+    let questions = question_repository
+        .get_questions(presentation_id)
+        .await?
+        .iter()
+        .filter(|question| question.status.is_public())
+        .map(|question| question.to_response_dto(None, false))
+        .collect();
+
+    Ok(QuestionBoardDTO {
+        presentation_id: presentation_id.to_string(),
+        questions,
+    })
+}
+
+//?POST:: api/presentations/presentation_id/questions
+pub async fn ask_question(
+    Extension(db): Extension<Arc<Database>>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<AskQuestionDTO>,
+) -> Result<Json<ApiResponse<QuestionResponseDTO>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let question_repository = QuestionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id)
+        .await?;
+    // Pending until a moderator approves it, so the board doesn't change yet
+    let question = question_repository
+        .create_question(&presentation.presentationId, payload.text, user_id.clone())
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Question submitted successfully"),
+        data: Some(question.to_response_dto(Some(&user_id), false)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/presentations/presentation_id/questions
+pub async fn get_questions(
+    Extension(db): Extension<Arc<Database>>,
+    Path(presentation_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<QuestionResponseDTO>>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let question_repository = QuestionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id)
+        .await?;
+    // Moderators see every question, the audience the public ones and their own
+    let can_moderate = presentation.can_moderate(&user_id);
+    let questions = question_repository
+        .get_questions(&presentation.presentationId)
+        .await?
+        .iter()
+        .filter(|question| {
+            can_moderate || question.status.is_public() || question.askedBy == user_id
+        })
+        .map(|question| question.to_response_dto(Some(&user_id), can_moderate))
+        .collect();
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Questions fetched successfully"),
+        data: Some(questions),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/presentations/presentation_id/questions/question_id/upvote
+pub async fn upvote_question(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path((presentation_id, question_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<QuestionResponseDTO>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let question_repository = QuestionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id.clone())
+        .await?;
+    let question = question_repository
+        .find_question(&presentation_id, &question_id)
+        .await?;
+    if !question.status.is_public() {
+        return Err(AppError::Poll(PollsError::QuestionNotFound));
+    }
+    if has_voted(&question.upvotedBy, &user_id) {
+        return Err(AppError::Poll(PollsError::AlreadyUpvoted));
+    }
+
+    let question = question_repository.upvote(&question_id, &user_id).await?;
+    publish_board(
+        &question_repository,
+        &app_state.presentations,
+        &presentation_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Question upvoted successfully"),
+        data: Some(question.to_response_dto(Some(&user_id), presentation.can_moderate(&user_id))),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/presentations/presentation_id/questions/question_id/upvote
+pub async fn remove_question_upvote(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path((presentation_id, question_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let question_repository = QuestionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    question_repository
+        .find_question(&presentation_id, &question_id)
+        .await?;
+    question_repository
+        .remove_upvote(&question_id, &user_id)
+        .await?;
+    publish_board(
+        &question_repository,
+        &app_state.presentations,
+        &presentation_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Upvote removed successfully"),
+        data: Some(String::from("Upvote removed successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?PATCH:: api/presentations/presentation_id/questions/question_id
+pub async fn moderate_question(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path((presentation_id, question_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    Json(payload): Json<ModerateQuestionDTO>,
+) -> Result<Json<ApiResponse<QuestionResponseDTO>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let question_repository = QuestionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id.clone())
+        .await?;
+    if !presentation.can_moderate(&user_id) {
+        return Err(AppError::Poll(PollsError::Unauthorized));
+    }
+
+    question_repository
+        .find_question(&presentation_id, &question_id)
+        .await?;
+    question_repository
+        .set_status(&question_id, payload.status)
+        .await?;
+    let question = question_repository
+        .find_question(&presentation_id, &question_id)
+        .await?;
+    publish_board(
+        &question_repository,
+        &app_state.presentations,
+        &presentation_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Question updated successfully"),
+        data: Some(question.to_response_dto(Some(&user_id), true)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/presentations/presentation_id/questions/live
+pub async fn get_question_stream(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(app_state): Extension<AppState>,
+    Path(presentation_id): Path<String>,
+) -> Result<Sse<impl Stream<Item = Result<Event, AppError>>>, AppError> {
+    let presentation_repository = PresentationRepository::new(db.clone());
+    let question_repository = QuestionRepository::new(db);
+
+    // Public like the presentation stream. Subscribe before reading the questions so
+    // changes in between aren't missed.
+    let boards = app_state
+        .presentations
+        .questions
+        .subscribe(&presentation_id);
+    let presentation = presentation_repository
+        .get_presentation_by_id(presentation_id)
+        .await?;
+    let board = question_board(&question_repository, &presentation.presentationId).await?;
+
+    let stream = tokio_stream::once(Arc::new(board))
+        .chain(boards)
+        .map(|board| {
+            Ok(Event::default()
+                .event("questions")
+                .data(serde_json::to_string(&*board).unwrap_or_default()))
+        });
+
+    Ok(Sse::new(stream).keep_alive(
+        axum::response::sse::KeepAlive::new()
+            .interval(Duration::from_secs(1))
+            .text("keep-alive-text"),
+    ))
+}
@@ -0,0 +1,179 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::CreatePollSeriesDTO,
+        responses::{ApiResponse, PollSeriesResponseDTO, SeriesTrendsDTO},
+    },
+    error::{AppError, PollsError},
+    models::poll_series::PollSeries,
+    repositories::{poll_repository::PollRepository, poll_series_repository::PollSeriesRepository},
+    utils::validation::ValidatedJson,
+};
+
+// Helper function to fetch a series only its creator may manage
+async fn get_owned_series(
+    series_repository: &PollSeriesRepository,
+    series_id: String,
+    user_id: &str,
+) -> Result<PollSeries, AppError> {
+    let series = series_repository.get_series_by_id(series_id).await?;
+    if series.createdBy != user_id {
+        return Err(AppError::Poll(PollsError::Unauthorized));
+    }
+    Ok(series)
+}
+
+//?POST:: api/series
+pub async fn create_series(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<CreatePollSeriesDTO>,
+) -> Result<Json<ApiResponse<PollSeriesResponseDTO>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let series = series_repository.create_series(payload, user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Poll series created successfully"),
+        data: Some(series),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/series
+pub async fn get_user_series(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PollSeriesResponseDTO>>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let series = series_repository.get_series_of_user(user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll series fetched successfully"),
+        data: Some(series),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/series/series_id
+pub async fn get_series_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(series_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollSeriesResponseDTO>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let series = get_owned_series(&series_repository, series_id, &user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll series retrieved successfully"),
+        data: Some(series.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/series/series_id/pause
+pub async fn pause_series(
+    Extension(db): Extension<Arc<Database>>,
+    Path(series_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollSeriesResponseDTO>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let series = get_owned_series(&series_repository, series_id, &user_id).await?;
+    let series = series_repository.set_active(&series, false).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll series paused successfully"),
+        data: Some(series),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/series/series_id/resume
+pub async fn resume_series(
+    Extension(db): Extension<Arc<Database>>,
+    Path(series_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollSeriesResponseDTO>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let series = get_owned_series(&series_repository, series_id, &user_id).await?;
+    let series = series_repository.set_active(&series, true).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll series resumed successfully"),
+        data: Some(series),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/series/series_id
+pub async fn delete_series(
+    Extension(db): Extension<Arc<Database>>,
+    Path(series_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    get_owned_series(&series_repository, series_id.clone(), &user_id).await?;
+    series_repository.delete_series(series_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll series deleted successfully"),
+        data: Some(String::from("Poll series deleted successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/series/series_id/trends
+pub async fn get_series_trends(
+    Extension(db): Extension<Arc<Database>>,
+    Path(series_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<SeriesTrendsDTO>>, AppError> {
+    let series_repository = PollSeriesRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let series = get_owned_series(&series_repository, series_id, &user_id).await?;
+    let instances = poll_repository
+        .get_polls_of_series(&series.seriesId)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Poll series trends retrieved successfully"),
+        data: Some(series.trends(instances)),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
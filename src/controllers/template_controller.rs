@@ -0,0 +1,144 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    config::validation::ValidationLimits,
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::{CreateTemplateDTO, InstantiateTemplateDTO},
+        responses::{ApiResponse, PollResponseDTO, PollTemplateResponseDTO},
+    },
+    error::AppError,
+    repositories::{
+        poll_repository::PollRepository, poll_revision_repository::PollRevisionRepository,
+        poll_template_repository::PollTemplateRepository,
+    },
+    utils::validation::{Validate, ValidatedJson},
+};
+
+//?POST:: api/templates
+pub async fn create_template(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<CreateTemplateDTO>,
+) -> Result<Json<ApiResponse<PollTemplateResponseDTO>>, AppError> {
+    let template_repository = PollTemplateRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let template = template_repository
+        .create_template(payload, user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Template created successfully"),
+        data: Some(template),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/templates
+pub async fn get_user_templates(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<PollTemplateResponseDTO>>>, AppError> {
+    let template_repository = PollTemplateRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let templates = template_repository.get_templates_of_user(user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Templates fetched successfully"),
+        data: Some(templates),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/templates/template_id
+pub async fn get_template_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(template_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<PollTemplateResponseDTO>>, AppError> {
+    let template_repository = PollTemplateRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let template = template_repository
+        .get_template_of_user(template_id, &user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Template retrieved successfully"),
+        data: Some(template.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/templates/template_id
+pub async fn delete_template_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(template_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let template_repository = PollTemplateRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify ownership
+    template_repository
+        .get_template_of_user(template_id.clone(), &user_id)
+        .await?;
+    template_repository.delete_template(template_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Template deleted successfully"),
+        data: Some(String::from("Template deleted successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/templates/template_id/instantiate
+pub async fn instantiate_template(
+    Extension(db): Extension<Arc<Database>>,
+    Extension(limits): Extension<ValidationLimits>,
+    Path(template_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<InstantiateTemplateDTO>,
+) -> Result<Json<ApiResponse<PollResponseDTO>>, AppError> {
+    let template_repository = PollTemplateRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let template = template_repository
+        .get_template_of_user(template_id, &user_id)
+        .await?;
+
+    // Filled in placeholders can break what the template alone satisfied, e.g. leave
+    // options blank, repeat one or run over the length limits
+    let new_poll = template.instantiate(payload, user_id)?;
+    new_poll.validate(&limits).map_err(AppError::Validation)?;
+
+    let poll = poll_repository.create_poll(new_poll).await?;
+    revision_repository.record_creation(&poll).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("Poll created from template successfully"),
+        data: Some(poll),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
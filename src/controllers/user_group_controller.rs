@@ -0,0 +1,163 @@
+use axum::{extract::Path, http::StatusCode, Extension, Json};
+use axum_extra::{
+    headers::{authorization::Bearer, Authorization},
+    TypedHeader,
+};
+use chrono::Utc;
+use mongodb::Database;
+use std::sync::Arc;
+
+use crate::{
+    controllers::poll_controller::get_user_id_from_token,
+    dtos::{
+        requests::{AllowUserDTO, CreateUserGroupDTO},
+        responses::{ApiResponse, UserGroupResponseDTO},
+    },
+    error::AppError,
+    repositories::user_group_repository::UserGroupRepository,
+    utils::validation::ValidatedJson,
+};
+
+//?POST:: api/groups
+pub async fn create_user_group(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<CreateUserGroupDTO>,
+) -> Result<Json<ApiResponse<UserGroupResponseDTO>>, AppError> {
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let group = group_repository.create_group(payload.name, user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::CREATED.as_u16() as i32,
+        message: String::from("User group created successfully"),
+        data: Some(group),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/groups
+pub async fn get_user_groups(
+    Extension(db): Extension<Arc<Database>>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<Vec<UserGroupResponseDTO>>>, AppError> {
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let groups = group_repository.get_groups_of_user(&user_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("User groups fetched successfully"),
+        data: Some(groups),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*GET:: api/groups/group_id
+pub async fn get_user_group_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(group_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<UserGroupResponseDTO>>, AppError> {
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    let group = group_repository
+        .get_group_of_owner(&group_id, &user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("User group retrieved successfully"),
+        data: Some(group.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/groups/group_id
+pub async fn delete_user_group_by_id(
+    Extension(db): Extension<Arc<Database>>,
+    Path(group_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<String>>, AppError> {
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify ownership
+    group_repository
+        .get_group_of_owner(&group_id, &user_id)
+        .await?;
+    group_repository.delete_group(&group_id).await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("User group deleted successfully"),
+        data: Some(String::from("User group deleted successfully")),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//?POST:: api/groups/group_id/members
+pub async fn add_group_member(
+    Extension(db): Extension<Arc<Database>>,
+    Path(group_id): Path<String>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+    ValidatedJson(payload): ValidatedJson<AllowUserDTO>,
+) -> Result<Json<ApiResponse<UserGroupResponseDTO>>, AppError> {
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify ownership
+    group_repository
+        .get_group_of_owner(&group_id, &user_id)
+        .await?;
+    group_repository
+        .add_member(&group_id, &payload.userId)
+        .await?;
+    let group = group_repository
+        .get_group_of_owner(&group_id, &user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Member added successfully"),
+        data: Some(group.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
+
+//*DELETE:: api/groups/group_id/members/user_id
+pub async fn remove_group_member(
+    Extension(db): Extension<Arc<Database>>,
+    Path((group_id, member_id)): Path<(String, String)>,
+    TypedHeader(authorization): TypedHeader<Authorization<Bearer>>,
+) -> Result<Json<ApiResponse<UserGroupResponseDTO>>, AppError> {
+    let group_repository = UserGroupRepository::new(db);
+    let user_id = get_user_id_from_token(authorization.token()).await?;
+
+    // Verify ownership
+    group_repository
+        .get_group_of_owner(&group_id, &user_id)
+        .await?;
+    group_repository
+        .remove_member(&group_id, &member_id)
+        .await?;
+    let group = group_repository
+        .get_group_of_owner(&group_id, &user_id)
+        .await?;
+
+    Ok(Json(ApiResponse {
+        status: StatusCode::OK.as_u16() as i32,
+        message: String::from("Member removed successfully"),
+        data: Some(group.to_response_dto()),
+        timestamp: Utc::now(),
+        error: None,
+    }))
+}
@@ -1,2 +1,3 @@
 pub mod requests;
 pub mod responses;
+pub mod validation;
@@ -1,15 +1,30 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use crate::models::{
+    organisation::OrgRole,
+    poll::{CollaboratorRole, Reaction, ResultsVisibility},
+    poll_series::RecurrenceRule,
+    question::QuestionStatus,
+};
 
 #[derive(Deserialize, Clone)]
 pub struct RegisterQuery {
     pub username: String,
 }
 
+#[allow(non_snake_case)]
 #[derive(Deserialize, Clone)]
 pub struct ResultQueryParams {
     pub live: Option<bool>,
-    pub authToken: Option<String>,
+    /// Live stream token of private polls, EventSource can't send headers
+    pub streamToken: Option<String>,
+    /// Poll access token of password protected polls, EventSource can't send headers
+    pub accessToken: Option<String>,
+    /// Resumes a stream like the `Last-Event-ID` header, which takes precedence
+    pub lastEventId: Option<u64>,
 }
 
 #[allow(non_snake_case)]
@@ -28,6 +43,36 @@ pub struct CreatePollDTO {
     pub createdBy: String,
 
     pub options: Vec<String>,
+
+    #[serde(default)]
+    pub isPrivate: bool,
+
+    /// Organisation that owns the poll, the creator must be at least an editor in it
+    #[serde(default)]
+    pub orgId: Option<String>,
+
+    /// User groups allowed to vote, empty lets everyone with access vote
+    #[serde(default)]
+    pub targetGroups: Vec<String>,
+
+    /// Lets people vote without an account using a guest token
+    #[serde(default)]
+    pub allowGuests: bool,
+    /// Also reject guest votes from a device (IP and user agent) that already voted
+    #[serde(default)]
+    pub guestFingerprinting: bool,
+
+    /// When people who don't manage the poll get to see vote counts
+    #[serde(default)]
+    pub resultsVisibility: ResultsVisibility,
+
+    /// Show each voter the options in an order of their own
+    #[serde(default)]
+    pub shuffleOptions: bool,
+
+    /// Only set by the series scheduler
+    #[serde(skip)]
+    pub seriesId: Option<String>,
 }
 
 #[allow(non_snake_case)]
@@ -44,6 +89,40 @@ pub struct UpdatePollDTO {
     pub isMulti: bool,
     pub startDate: String,
     pub endDate: String,
+
+    /// Full, ordered list of options the poll should have after the update.
+    /// Leave out to keep the current options untouched.
+    pub options: Option<Vec<UpdatePollOptionDTO>>,
+    /// What to do with the votes of removed options, required only if they have any
+    pub removedVotes: Option<RemovedVotesStrategy>,
+    /// Leave out to keep the current visibility
+    pub isPrivate: Option<bool>,
+    /// Leave out to keep the current target groups, an empty list opens voting to everyone
+    pub targetGroups: Option<Vec<String>>,
+    /// Leave out to keep the current guest settings
+    pub allowGuests: Option<bool>,
+    pub guestFingerprinting: Option<bool>,
+    /// Leave out to keep the current results visibility
+    pub resultsVisibility: Option<ResultsVisibility>,
+    pub shuffleOptions: Option<bool>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct UpdatePollOptionDTO {
+    /// Existing option to keep (and possibly rename), `None` adds a new option
+    pub optionId: Option<String>,
+    pub optionName: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "strategy", rename_all = "camelCase")]
+pub enum RemovedVotesStrategy {
+    /// Move the votes of removed options onto an option that is kept
+    Migrate { targetOptionId: String },
+    /// Zero every count and let everyone vote again
+    Reset,
 }
 
 #[allow(non_snake_case)]
@@ -53,4 +132,235 @@ pub struct UpdatePollReq {
     pub isMulti: bool,
     pub startDate: DateTime<Utc>,
     pub endDate: DateTime<Utc>,
+    pub options: Option<Vec<UpdatePollOptionDTO>>,
+    pub removedVotes: Option<RemovedVotesStrategy>,
+    pub isPrivate: Option<bool>,
+    pub targetGroups: Option<Vec<String>>,
+    pub allowGuests: Option<bool>,
+    pub guestFingerprinting: Option<bool>,
+    pub resultsVisibility: Option<ResultsVisibility>,
+    pub shuffleOptions: Option<bool>,
+}
+
+#[derive(Deserialize, Clone)]
+pub struct RevisionDiffQueryParams {
+    pub from: i32,
+    /// Defaults to the latest revision
+    pub to: Option<i32>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct ClonePollDTO {
+    /// Defaults to the name of the poll being cloned
+    pub name: Option<String>,
+    pub startDate: DateTime<Utc>,
+    pub endDate: DateTime<Utc>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreateTemplateDTO {
+    /// Label of the template in the library
+    pub title: String,
+    /// Poll name, may contain `{{param}}` placeholders
+    pub name: String,
+    pub isMulti: bool,
+    /// Option names, may contain `{{param}}` placeholders
+    pub options: Vec<String>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct InstantiateTemplateDTO {
+    #[serde(default)]
+    pub params: HashMap<String, String>,
+    pub startDate: DateTime<Utc>,
+    pub endDate: DateTime<Utc>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreatePollSeriesDTO {
+    /// Poll name, `{{date}}` is replaced with each instance's local date
+    pub name: String,
+    pub isMulti: bool,
+    pub options: Vec<String>,
+    pub recurrence: RecurrenceRule,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct RedeemInviteDTO {
+    pub inviteCode: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct AllowUserDTO {
+    pub userId: String,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ImageFormat {
+    #[default]
+    Png,
+    Svg,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+pub enum QrErrorCorrection {
+    L,
+    #[default]
+    M,
+    Q,
+    H,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct QrQueryParams {
+    pub format: Option<ImageFormat>,
+    /// Width and height in pixels
+    pub size: Option<u32>,
+    pub ecc: Option<QrErrorCorrection>,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartKind {
+    #[default]
+    Bar,
+    Pie,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartTheme {
+    #[default]
+    Light,
+    Dark,
+}
+
+/// What the options of a chart are labelled with
+#[derive(Deserialize, Clone, Copy, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum ChartLabels {
+    #[default]
+    Percentage, // Share of all votes
+    Absolute, // Vote count
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ChartQueryParams {
+    pub format: Option<ImageFormat>,
+    pub chart: Option<ChartKind>,
+    pub theme: Option<ChartTheme>,
+    pub labels: Option<ChartLabels>,
+    /// In pixels
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreateOrganisationDTO {
+    pub name: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct JoinOrganisationDTO {
+    pub joinCode: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct UpdateMemberRoleDTO {
+    pub role: OrgRole,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct InviteCollaboratorDTO {
+    pub userId: String,
+    pub role: CollaboratorRole,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreateUserGroupDTO {
+    pub name: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PollPasswordDTO {
+    pub password: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreateCommentDTO {
+    pub text: String,
+    pub parentId: Option<String>, // Replies to this comment
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct EditCommentDTO {
+    pub text: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct CommentQueryParams {
+    /// Starts at 1
+    pub page: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct PollReactionDTO {
+    pub reaction: Reaction,
+}
+
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+pub struct CreatePresentationDTO {
+    pub title: String,
+    pub pollIds: Vec<String>, // Presented in this order
+    #[serde(default)]
+    pub moderators: Vec<String>, // Users moderating the Q&A besides the presenter
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct AskQuestionDTO {
+    pub text: String,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+pub struct ModerateQuestionDTO {
+    pub status: QuestionStatus,
+}
+
+/// Messages clients send over the live polls WebSocket
+#[allow(non_snake_case)]
+#[derive(Deserialize, Clone, Debug)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum LiveClientMessage {
+    /// The access token is needed for password protected polls and kept for votes on them
+    Subscribe {
+        pollId: String,
+        accessToken: Option<String>,
+    },
+    Unsubscribe {
+        pollId: String,
+    },
+    /// Echoes `requestId` in the acknowledgement or error
+    Vote {
+        pollId: String,
+        optionId: String,
+        requestId: Option<String>,
+        accessToken: Option<String>,
+    },
+    React {
+        pollId: String,
+        reaction: Reaction,
+        accessToken: Option<String>,
+    },
 }
@@ -1,7 +1,18 @@
+use std::collections::BTreeMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 use webauthn_rs::prelude::*;
 
+use crate::models::{
+    organisation::OrgRole,
+    poll::{CollaboratorRole, Reaction, ResultsVisibility},
+    poll_series::RecurrenceRule,
+    presentation::PresentationPhase,
+    question::QuestionStatus,
+};
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApiResponse<T> {
@@ -58,12 +69,575 @@ pub struct PollResponseDTO {
     pub end_date: String,
     pub options: Vec<PollOptionResponseDTO>,
     pub voted_by: Vec<String>,
+    pub series_id: Option<String>,
+    pub org_id: Option<String>,
+    pub target_groups: Vec<String>,
+    pub allow_guests: bool,
+    pub guest_fingerprinting: bool,
+    pub results_visibility: ResultsVisibility,
+    /// Set when vote counts were left out because of the results visibility
+    pub results_hidden: bool,
+    pub shuffle_options: bool,
+    pub has_password: bool,
+    pub is_private: bool,
+    pub join_code: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct PollOptionResponseDTO {
     pub option_id: String,
     pub option_name: String,
+    /// `null` while results are hidden from the user
+    pub votes: Option<i32>,
+    pub display_order: i32,
+}
+
+impl PollResponseDTO {
+    /// Voters go too, their number would give the turnout away
+    pub fn without_results(mut self) -> Self {
+        self.options.iter_mut().for_each(|opt| opt.votes = None);
+        self.voted_by.clear();
+        self.results_hidden = true;
+        self
+    }
+
+    /// Join codes are only shared with those managing access to the poll
+    pub fn without_join_code(mut self) -> Self {
+        self.join_code = None;
+        self
+    }
+
+    /// Who voted is only shared with those allowed to view the voters
+    pub fn without_voters(mut self) -> Self {
+        self.voted_by.clear();
+        self
+    }
+
+    /// Orders the options by a hash of poll, user and option ids, so every voter gets a
+    /// different order that stays the same across reloads
+    pub fn with_options_shuffled_for(mut self, user_id: &str) -> Self {
+        let poll_id = &self.poll_id;
+        self.options.sort_by_cached_key(|opt| {
+            Sha256::digest(format!("{}:{}:{}", poll_id, user_id, opt.option_id))
+        });
+        self
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollRevisionResponseDTO {
+    pub revision: i32,
+    pub edited_by: String,
+    pub edited_at: String,
+    pub changes: Vec<FieldChangeDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChangeDTO {
+    pub field: String,
+    pub before: serde_json::Value,
+    pub after: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollRevisionDiffDTO {
+    pub poll_id: String,
+    pub from_revision: i32,
+    pub to_revision: i32,
+    pub changes: Vec<FieldChangeDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollTemplateResponseDTO {
+    pub template_id: String,
+    pub created_by: String,
+    pub title: String,
+    pub name: String,
+    pub is_multi: bool,
+    pub options: Vec<String>,
+    pub parameters: Vec<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollSeriesResponseDTO {
+    pub series_id: String,
+    pub created_by: String,
+    pub name: String,
+    pub is_multi: bool,
+    pub options: Vec<String>,
+    pub recurrence: RecurrenceRule,
+    pub next_run_at: String,
+    pub is_active: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesTrendsDTO {
+    pub series_id: String,
+    /// Generated polls, oldest first
+    pub instances: Vec<SeriesInstanceDTO>,
+    /// Votes per option name across instances, aligned with `instances`
+    pub option_trends: Vec<OptionTrendDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SeriesInstanceDTO {
+    pub poll_id: String,
+    pub name: String,
+    pub start_date: String,
+    pub end_date: String,
+    pub is_closed: bool,
+    pub total_votes: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionTrendDTO {
+    pub option_name: String,
+    pub votes: Vec<i32>,
+    pub percentages: Vec<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollAccessResponseDTO {
+    pub poll_id: String,
+    pub is_private: bool,
+    pub invite_code: Option<String>,
+    pub allowed_users: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JoinCodeResponseDTO {
+    pub poll_id: String,
+    pub name: String,
+    pub join_code: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganisationResponseDTO {
+    pub org_id: String,
+    pub name: String,
+    pub created_by: String,
+    /// Only shown to admins and owners
+    pub join_code: Option<String>,
+    /// Role of the requesting user
+    pub role: Option<OrgRole>,
+    pub members: Vec<OrganisationMemberDTO>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrganisationMemberDTO {
+    pub user_id: String,
+    pub role: OrgRole,
+    pub joined_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollCollaboratorDTO {
+    pub user_id: String,
+    pub role: CollaboratorRole,
+    pub accepted: bool,
+    pub invited_by: String,
+    pub invited_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CollaboratorInviteDTO {
+    pub poll_id: String,
+    pub poll_name: String,
+    pub role: CollaboratorRole,
+    pub invited_by: String,
+    pub invited_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollVoterDTO {
+    pub user_id: String,
+    /// Missing for accounts that no longer exist
+    pub username: Option<String>,
+}
+
+/// Audience of a poll next to its turnout
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollAnalyticsDTO {
+    pub poll_id: String,
+    pub total_votes: i32,
+    pub voters: usize,
+    /// Connected to the live results right now, on this instance
+    pub current_viewers: usize,
+    pub peak_viewers: usize,
+    pub peak_viewers_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresentationResponseDTO {
+    pub presentation_id: String,
+    pub created_by: String,
+    pub title: String,
+    pub poll_ids: Vec<String>,
+    pub moderators: Vec<String>,
+    pub position: i32,
+    pub phase: PresentationPhase,
+    pub created_at: String,
+}
+
+/// What the audience of a presentation is shown, pushed on every presenter action
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresentationStateDTO {
+    pub presentation_id: String,
+    pub title: String,
+    pub phase: PresentationPhase,
+    pub position: i32,
+    pub poll_count: usize,
+    /// The current poll, with vote counts only once revealed
+    pub poll: Option<PollResponseDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionResponseDTO {
+    pub question_id: String,
+    pub presentation_id: String,
+    pub text: String,
+    /// Missing on the public question board
+    pub asked_by: Option<String>,
+    pub status: QuestionStatus,
+    pub upvotes: i32,
+    /// Whether the requesting user upvoted it
+    pub upvoted: bool,
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentResponseDTO {
+    pub comment_id: String,
+    pub poll_id: String,
+    pub parent_id: Option<String>,
+    /// `null` once deleted, like the text
+    pub author_id: Option<String>,
+    pub text: Option<String>,
+    pub reply_count: i32,
+    pub is_deleted: bool,
+    pub created_at: String,
+    pub edited_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentPageDTO {
+    pub comments: Vec<CommentResponseDTO>,
+    pub page: u64,
+    pub limit: u64,
+    pub total: u64,
+}
+
+/// Questions the whole audience sees, ranked
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionBoardDTO {
+    pub presentation_id: String,
+    pub questions: Vec<QuestionResponseDTO>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserGroupResponseDTO {
+    pub group_id: String,
+    pub name: String,
+    pub created_by: String,
+    pub members: Vec<String>,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GuestTokenResponseDTO {
+    pub poll_id: String,
+    pub guest_id: String,
+    pub guest_token: String,
+    pub expires_at: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PollAccessTokenDTO {
+    pub poll_id: String,
+    /// Sent back in the `X-Poll-Access-Token` header
+    pub access_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveStreamTokenDTO {
+    pub poll_id: String,
+    /// Sent back in the `streamToken` query parameter of the live results
+    pub stream_token: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// State of a poll pushed to its live viewers
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveResults {
+    pub poll_id: String,
+    pub options: Vec<PollOptionResponseDTO>,
+    pub is_paused: bool,
+    pub is_closed: bool,
+}
+
+impl From<&PollResponseDTO> for LiveResults {
+    fn from(poll: &PollResponseDTO) -> Self {
+        LiveResults {
+            poll_id: poll.poll_id.clone(),
+            options: poll.options.clone(),
+            is_paused: poll.is_paused,
+            is_closed: poll.is_closed,
+        }
+    }
+}
+
+/// Version of the live event schema, bumped on incompatible changes
+pub const LIVE_EVENT_VERSION: u8 = 1;
+
+/// Event on the live stream of a poll, wrapped in a `LiveUpdate`
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(
+    tag = "type",
+    content = "data",
+    rename_all = "kebab-case",
+    rename_all_fields = "camelCase"
+)]
+pub enum LiveEvent {
+    /// Full state, replaces whatever the client had
+    Snapshot(LiveResults),
+    VoteDelta {
+        changes: Vec<OptionVoteDelta>,
+    },
+    OptionAdded {
+        option: PollOptionResponseDTO,
+    },
+    OptionUpdated {
+        option: PollOptionResponseDTO, // Renamed or moved
+    },
+    OptionRemoved {
+        option_id: String,
+    },
+    Paused,
+    Resumed,
+    Closed,
+    /// All votes were cleared
+    Reset {
+        options: Vec<PollOptionResponseDTO>,
+    },
+    /// Debounced, sent once the count settles
+    ViewerCount {
+        viewers: usize,
+    },
+    /// Reactions sent over the last second, per emoji
+    Reactions {
+        counts: BTreeMap<Reaction, u32>,
+    },
+    CommentAdded {
+        comment: CommentResponseDTO,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionVoteDelta {
+    pub option_id: String,
+    pub delta: i32,
     pub votes: i32,
 }
+
+/// Envelope of live events: `{ "v", "seq", "pollId", "type", "data" }`. Sequence numbers
+/// grow with every event of any poll and double as SSE event ids.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LiveUpdate {
+    pub v: u8,
+    pub seq: u64,
+    pub poll_id: String,
+    #[serde(flatten)]
+    pub event: LiveEvent,
+}
+
+impl LiveUpdate {
+    pub fn new(seq: u64, poll_id: String, event: LiveEvent) -> Self {
+        LiveUpdate {
+            v: LIVE_EVENT_VERSION,
+            seq,
+            poll_id,
+            event,
+        }
+    }
+
+    /// Name of the event, used as the SSE event type
+    pub fn event_type(&self) -> &'static str {
+        match self.event {
+            LiveEvent::Snapshot(_) => "snapshot",
+            LiveEvent::VoteDelta { .. } => "vote-delta",
+            LiveEvent::OptionAdded { .. } => "option-added",
+            LiveEvent::OptionUpdated { .. } => "option-updated",
+            LiveEvent::OptionRemoved { .. } => "option-removed",
+            LiveEvent::Paused => "paused",
+            LiveEvent::Resumed => "resumed",
+            LiveEvent::Closed => "closed",
+            LiveEvent::Reset { .. } => "reset",
+            LiveEvent::ViewerCount { .. } => "viewer-count",
+            LiveEvent::Reactions { .. } => "reactions",
+            LiveEvent::CommentAdded { .. } => "comment-added",
+        }
+    }
+}
+
+/// Messages sent over the live polls WebSocket
+#[derive(Debug, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum LiveServerMessage {
+    Subscribed {
+        poll_id: String,
+    },
+    Unsubscribed {
+        poll_id: String,
+    },
+    /// Live event of a subscribed poll, starting with a snapshot right after subscribing
+    Event {
+        event: LiveUpdate,
+    },
+    VoteAck {
+        request_id: Option<String>,
+        poll: Box<PollResponseDTO>,
+    },
+    Reacted {
+        poll_id: String,
+    },
+    Error {
+        request_id: Option<String>,
+        poll_id: Option<String>,
+        message: String,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn poll(option_ids: &[&str]) -> PollResponseDTO {
+        PollResponseDTO {
+            poll_id: "poll".to_string(),
+            created_by: "owner".to_string(),
+            name: "Poll".to_string(),
+            is_multi: false,
+            is_paused: false,
+            is_closed: false,
+            start_date: String::new(),
+            end_date: String::new(),
+            options: option_ids
+                .iter()
+                .enumerate()
+                .map(|(index, option_id)| PollOptionResponseDTO {
+                    option_id: option_id.to_string(),
+                    option_name: option_id.to_uppercase(),
+                    votes: Some(0),
+                    display_order: index as i32,
+                })
+                .collect(),
+            voted_by: vec![],
+            series_id: None,
+            org_id: None,
+            target_groups: vec![],
+            allow_guests: false,
+            guest_fingerprinting: false,
+            results_visibility: ResultsVisibility::Always,
+            results_hidden: false,
+            shuffle_options: true,
+            has_password: false,
+            is_private: false,
+            join_code: None,
+        }
+    }
+
+    fn order(poll: &PollResponseDTO) -> Vec<&str> {
+        poll.options
+            .iter()
+            .map(|option| option.option_id.as_str())
+            .collect()
+    }
+
+    const OPTIONS: [&str; 6] = ["a", "b", "c", "d", "e", "f"];
+
+    #[test]
+    fn hidden_results_leave_out_counts_and_voters() {
+        let mut voted = poll(&OPTIONS);
+        voted.options[0].votes = Some(2);
+        voted.voted_by = vec!["alice".to_string(), "bob".to_string()];
+
+        let hidden = voted.without_results();
+
+        assert!(hidden.results_hidden);
+        assert!(hidden.options.iter().all(|option| option.votes.is_none()));
+        assert!(hidden.voted_by.is_empty());
+    }
+
+    #[test]
+    fn shuffle_is_stable_per_user() {
+        let first = poll(&OPTIONS).with_options_shuffled_for("user");
+        let again = poll(&OPTIONS).with_options_shuffled_for("user");
+
+        assert_eq!(order(&first), order(&again));
+    }
+
+    #[test]
+    fn shuffle_ignores_the_stored_order() {
+        let mut reversed = OPTIONS;
+        reversed.reverse();
+
+        assert_eq!(
+            order(&poll(&OPTIONS).with_options_shuffled_for("user")),
+            order(&poll(&reversed).with_options_shuffled_for("user"))
+        );
+    }
+
+    #[test]
+    fn shuffle_keeps_every_option() {
+        let shuffled = poll(&OPTIONS).with_options_shuffled_for("user");
+        let mut options = order(&shuffled);
+        options.sort();
+
+        assert_eq!(options, OPTIONS);
+    }
+
+    #[test]
+    fn users_get_different_orders() {
+        let orders = ["alice", "bob", "carol", "dave"]
+            .map(|user| order(&poll(&OPTIONS).with_options_shuffled_for(user)).join(""));
+
+        assert!(orders.iter().any(|order| *order != orders[0]));
+    }
+}
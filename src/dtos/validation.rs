@@ -0,0 +1,340 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::{
+    config::validation::ValidationLimits,
+    dtos::requests::{
+        AllowUserDTO, AskQuestionDTO, ChartQueryParams, ClonePollDTO, CommentQueryParams,
+        CreateCommentDTO, CreateOrganisationDTO, CreatePollDTO, CreatePollSeriesDTO,
+        CreatePresentationDTO, CreateTemplateDTO, CreateUserGroupDTO, EditCommentDTO,
+        InstantiateTemplateDTO, InviteCollaboratorDTO, JoinOrganisationDTO, PollPasswordDTO,
+        QrQueryParams, RedeemInviteDTO, RegisterQuery, UpdatePollDTO,
+    },
+    utils::{
+        recurrence,
+        validation::{Validate, ValidationErrors},
+    },
+};
+
+impl Validate for CreatePollDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        errors.check_text("name", &self.name, limits.max_name_length);
+        errors.check_options("options", self.options.iter().map(String::as_str), limits);
+        errors.check_dates(self.startDate, self.endDate);
+        errors.check_end_in_future(self.endDate);
+
+        errors.into_result()
+    }
+}
+
+impl Validate for UpdatePollDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        errors.check_text("name", &self.name, limits.max_name_length);
+
+        let parse = |errors: &mut ValidationErrors, field: &str, value: &str| {
+            match DateTime::parse_from_rfc3339(value) {
+                Ok(date) => Some(date.with_timezone(&Utc)),
+                Err(_) => {
+                    errors.add(
+                        field,
+                        "invalidPollDates",
+                        format!("{} must be an RFC 3339 date", field),
+                    );
+                    None
+                }
+            }
+        };
+        let start_date = parse(&mut errors, "startDate", &self.startDate);
+        let end_date = parse(&mut errors, "endDate", &self.endDate);
+        if let (Some(start_date), Some(end_date)) = (start_date, end_date) {
+            errors.check_dates(start_date, end_date);
+        }
+
+        if let Some(options) = &self.options {
+            errors.check_options(
+                "options",
+                options.iter().map(|opt| opt.optionName.as_str()),
+                limits,
+            );
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for ClonePollDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        if let Some(name) = &self.name {
+            errors.check_text("name", name, limits.max_name_length);
+        }
+        errors.check_dates(self.startDate, self.endDate);
+        errors.check_end_in_future(self.endDate);
+
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateTemplateDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        errors.check_text("title", &self.title, limits.max_name_length);
+        errors.check_text("name", &self.name, limits.max_name_length);
+        errors.check_options("options", self.options.iter().map(String::as_str), limits);
+
+        errors.into_result()
+    }
+}
+
+impl Validate for InstantiateTemplateDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        for (param, value) in &self.params {
+            errors.check_text(
+                &format!("params.{}", param),
+                value,
+                limits.max_option_length,
+            );
+        }
+        errors.check_dates(self.startDate, self.endDate);
+
+        errors.into_result()
+    }
+}
+
+impl Validate for CreatePollSeriesDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        errors.check_text("name", &self.name, limits.max_name_length);
+        errors.check_options("options", self.options.iter().map(String::as_str), limits);
+        if let Err(message) = recurrence::validate(&self.recurrence) {
+            errors.add("recurrence", "invalidConfiguration", message);
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for RegisterQuery {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        let length = self.username.chars().count();
+        if length < limits.min_username_length || length > limits.max_username_length {
+            errors.add(
+                "username",
+                "invalidLength",
+                format!(
+                    "username must be between {} and {} characters",
+                    limits.min_username_length, limits.max_username_length
+                ),
+            );
+        }
+        if !self
+            .username
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || c == '.')
+        {
+            errors.add(
+                "username",
+                "invalidUsername",
+                "username may only contain letters, digits, '_', '-' and '.'",
+            );
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for RedeemInviteDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("inviteCode", &self.inviteCode, limits.max_option_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for AllowUserDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("userId", &self.userId, limits.max_option_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for QrQueryParams {
+    fn validate(&self, _limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        if let Some(size) = self.size {
+            if !(64..=2048).contains(&size) {
+                errors.add("size", "outOfRange", "size must be between 64 and 2048");
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for ChartQueryParams {
+    fn validate(&self, _limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        for (field, value) in [("width", self.width), ("height", self.height)] {
+            if let Some(value) = value {
+                if !(200..=2048).contains(&value) {
+                    errors.add(
+                        field,
+                        "outOfRange",
+                        format!("{} must be between 200 and 2048", field),
+                    );
+                }
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateOrganisationDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("name", &self.name, limits.max_name_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for JoinOrganisationDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("joinCode", &self.joinCode, limits.max_option_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for InviteCollaboratorDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("userId", &self.userId, limits.max_option_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateUserGroupDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("name", &self.name, limits.max_name_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for CreatePresentationDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        errors.check_text("title", &self.title, limits.max_name_length);
+        if self.pollIds.is_empty() {
+            errors.add(
+                "pollIds",
+                "tooFew",
+                "A presentation needs at least one poll",
+            );
+        } else if self.pollIds.len() > limits.max_presentation_polls {
+            errors.add(
+                "pollIds",
+                "tooMany",
+                format!(
+                    "A presentation can have at most {} polls",
+                    limits.max_presentation_polls
+                ),
+            );
+        }
+
+        let mut seen = HashSet::new();
+        for (index, poll_id) in self.pollIds.iter().enumerate() {
+            if !seen.insert(poll_id) {
+                errors.add(
+                    format!("pollIds[{}]", index),
+                    "duplicate",
+                    format!("Poll '{}' is listed more than once", poll_id),
+                );
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for AskQuestionDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        errors.check_text("text", &self.text, limits.max_question_length);
+
+        errors.into_result()
+    }
+}
+
+impl Validate for CreateCommentDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("text", &self.text, limits.max_comment_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for EditCommentDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+        errors.check_text("text", &self.text, limits.max_comment_length);
+        errors.into_result()
+    }
+}
+
+impl Validate for CommentQueryParams {
+    fn validate(&self, _limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        if let Some(page) = self.page {
+            if !(1..=10_000).contains(&page) {
+                errors.add("page", "outOfRange", "page must be between 1 and 10000");
+            }
+        }
+        if let Some(limit) = self.limit {
+            if !(1..=100).contains(&limit) {
+                errors.add("limit", "outOfRange", "limit must be between 1 and 100");
+            }
+        }
+
+        errors.into_result()
+    }
+}
+
+impl Validate for PollPasswordDTO {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::default();
+
+        let length = self.password.chars().count();
+        if length < limits.min_password_length || length > limits.max_password_length {
+            errors.add(
+                "password",
+                "invalidLength",
+                format!(
+                    "password must be between {} and {} characters",
+                    limits.min_password_length, limits.max_password_length
+                ),
+            );
+        }
+
+        errors.into_result()
+    }
+}
@@ -7,6 +7,8 @@ use axum::{
 use serde_json::json;
 use thiserror::Error;
 
+use crate::utils::validation::ValidationErrors;
+
 #[derive(Error, Debug)]
 pub enum AppError {
     #[error("Something's reeeeealy wrong, send hlp pls")]
@@ -47,6 +49,10 @@ pub enum AppError {
     // JWT Errors
     #[error("JWT error: {0}")]
     JwtError(#[from] JwtError),
+
+    // Request Validation Errors
+    #[error("Validation failed: {0}")]
+    Validation(ValidationErrors),
 }
 
 #[derive(Error, Debug)]
@@ -95,6 +101,78 @@ pub enum PollsError {
 
     #[error("Use alternative endpoint")]
     UseAlternativeEndpoint,
+
+    #[error("Removed options have votes, migrate or reset them first")]
+    OptionHasVotes,
+
+    #[error("Poll revision not found")]
+    RevisionNotFound,
+
+    #[error("Poll template not found")]
+    TemplateNotFound,
+
+    #[error("Poll series not found")]
+    SeriesNotFound,
+
+    #[error("This poll is private")]
+    PrivatePoll,
+
+    #[error("Invalid invite code")]
+    InvalidInviteCode,
+
+    #[error("No open poll uses this join code")]
+    JoinCodeNotFound,
+
+    #[error("Organisation not found")]
+    OrganisationNotFound,
+
+    #[error("You're not a member of this organisation")]
+    NotOrganisationMember,
+
+    #[error("Your role in this organisation doesn't allow this action")]
+    InsufficientOrgRole,
+
+    #[error("An organisation needs at least one owner")]
+    LastOrganisationOwner,
+
+    #[error("Collaborator or invitation not found")]
+    CollaboratorNotFound,
+
+    #[error("User group not found")]
+    GroupNotFound,
+
+    #[error("Voting on this poll is limited to members of its target groups")]
+    NotInTargetGroup,
+
+    #[error("This poll doesn't accept guest votes")]
+    GuestVotingDisabled,
+
+    #[error("Guest token is invalid or belongs to another poll")]
+    InvalidGuestToken,
+
+    #[error("Results of this poll aren't visible to you yet")]
+    ResultsHidden,
+
+    #[error("This poll is password protected, unlock it first")]
+    PasswordRequired,
+
+    #[error("Incorrect poll password")]
+    IncorrectPollPassword,
+
+    #[error("Presentation not found")]
+    PresentationNotFound,
+
+    #[error("Start the presentation first")]
+    PresentationNotRunning,
+
+    #[error("Question not found")]
+    QuestionNotFound,
+
+    #[error("You already upvoted this question")]
+    AlreadyUpvoted,
+
+    #[error("Comment not found")]
+    CommentNotFound,
 }
 
 #[derive(Error, Debug)]
@@ -132,6 +210,10 @@ pub enum JwtError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let error_string = self.to_string();
+        let field_errors = match &self {
+            AppError::Validation(errors) => Some(errors.errors.clone()),
+            _ => None,
+        };
         let (status, error_message) = match self {
             // Authentication & Session Errors
             AppError::SessionExpired => (StatusCode::UNAUTHORIZED, "Session Expired"),
@@ -176,7 +258,7 @@ impl IntoResponse for AppError {
             AppError::Poll(poll_err) => match poll_err {
                 PollsError::Unauthorized => (
                     StatusCode::FORBIDDEN,
-                    "You're not authorized to perform this action on this poll"
+                    "You're not authorized to perform this action on this poll",
                 ),
                 PollsError::NoPollsFoundForUser => (
                     StatusCode::NOT_FOUND,
@@ -207,6 +289,54 @@ impl IntoResponse for AppError {
                 PollsError::UseAlternativeEndpoint => {
                     (StatusCode::SEE_OTHER, "Use Alternative Endpoint")
                 }
+                PollsError::OptionHasVotes => (StatusCode::CONFLICT, "Removed Options Have Votes"),
+                PollsError::RevisionNotFound => (StatusCode::NOT_FOUND, "Revision Not Found"),
+                PollsError::TemplateNotFound => (StatusCode::NOT_FOUND, "Template Not Found"),
+                PollsError::SeriesNotFound => (StatusCode::NOT_FOUND, "Poll Series Not Found"),
+                PollsError::PrivatePoll => (StatusCode::FORBIDDEN, "Poll Is Private"),
+                PollsError::InvalidInviteCode => (StatusCode::FORBIDDEN, "Invalid Invite Code"),
+                PollsError::JoinCodeNotFound => (StatusCode::NOT_FOUND, "Join Code Not Found"),
+                PollsError::OrganisationNotFound => {
+                    (StatusCode::NOT_FOUND, "Organisation Not Found")
+                }
+                PollsError::NotOrganisationMember => {
+                    (StatusCode::FORBIDDEN, "Not An Organisation Member")
+                }
+                PollsError::InsufficientOrgRole => {
+                    (StatusCode::FORBIDDEN, "Insufficient Organisation Role")
+                }
+                PollsError::LastOrganisationOwner => {
+                    (StatusCode::CONFLICT, "Organisation Needs An Owner")
+                }
+                PollsError::CollaboratorNotFound => {
+                    (StatusCode::NOT_FOUND, "Collaborator Not Found")
+                }
+                PollsError::GroupNotFound => (StatusCode::NOT_FOUND, "User Group Not Found"),
+                PollsError::NotInTargetGroup => {
+                    (StatusCode::FORBIDDEN, "Not A Member Of The Target Groups")
+                }
+                PollsError::GuestVotingDisabled => {
+                    (StatusCode::FORBIDDEN, "Guest Voting Is Disabled")
+                }
+                PollsError::InvalidGuestToken => (StatusCode::UNAUTHORIZED, "Invalid Guest Token"),
+                PollsError::ResultsHidden => (StatusCode::FORBIDDEN, "Results Are Hidden"),
+                PollsError::PasswordRequired => {
+                    (StatusCode::UNAUTHORIZED, "Poll Password Required")
+                }
+                PollsError::IncorrectPollPassword => {
+                    (StatusCode::UNAUTHORIZED, "Incorrect Poll Password")
+                }
+                PollsError::PresentationNotFound => {
+                    (StatusCode::NOT_FOUND, "Presentation Not Found")
+                }
+                PollsError::PresentationNotRunning => {
+                    (StatusCode::CONFLICT, "Presentation Not Running")
+                }
+                PollsError::QuestionNotFound => (StatusCode::NOT_FOUND, "Question Not Found"),
+                PollsError::AlreadyUpvoted => {
+                    (StatusCode::CONFLICT, "Already Upvoted This Question")
+                }
+                PollsError::CommentNotFound => (StatusCode::NOT_FOUND, "Comment Not Found"),
             },
 
             // JWT Errors
@@ -233,16 +363,23 @@ impl IntoResponse for AppError {
                 ),
             },
 
+            AppError::Validation(_) => (StatusCode::UNPROCESSABLE_ENTITY, "Validation Failed"),
+
             AppError::Unknown => (StatusCode::INTERNAL_SERVER_ERROR, "Unknown Error"),
         };
 
-        let body = Json(json!({
+        let mut body = json!({
             "status": status.as_u16(),
             "message": error_message,
             "error": error_string,
             "timestamp": chrono::Utc::now()
-        }));
+        });
+
+        // Field level details so clients can point at the offending inputs
+        if let Some(field_errors) = field_errors {
+            body["errors"] = json!(field_errors);
+        }
 
-        (status, body).into_response()
+        (status, Json(body)).into_response()
     }
 }
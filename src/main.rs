@@ -1,11 +1,21 @@
 use axum::{Extension, Router};
 use config::{
     cors::init_cors, db, logger::initialize_logger, session::init_session, startup::AppState,
+    validation::ValidationLimits,
 };
 
 use dotenvy::dotenv;
+use std::net::SocketAddr;
 
-use routes::poll_route::poll_router;
+use repositories::{
+    poll_repository::PollRepository, poll_revision_repository::PollRevisionRepository,
+};
+use routes::{
+    organisation_route::organisation_router, poll_route::poll_router,
+    presentation_route::presentation_router, series_route::series_router,
+    template_route::template_router, user_group_route::user_group_router,
+};
+use services::{live_results::run_change_stream_listener, series_scheduler::run_series_scheduler};
 use tower_http::trace::TraceLayer;
 use tracing::{error, info};
 
@@ -17,6 +27,7 @@ mod middleware;
 mod models;
 mod repositories;
 mod routes;
+mod services;
 mod utils;
 
 use crate::routes::auth_route::auth_router;
@@ -42,23 +53,56 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         .await
         .expect("Failed to initialize database");
 
+    // Unique indexes the repositories rely on. Existing duplicates keep them from being built,
+    // which is logged rather than keeping the server down.
+    if let Err(e) = PollRepository::new(db.clone()).create_indexes().await {
+        error!("Failed to create poll indexes: {}", e);
+    }
+    if let Err(e) = PollRevisionRepository::new(db.clone())
+        .create_indexes()
+        .await
+    {
+        error!("Failed to create poll revision indexes: {}", e);
+    }
+
+    // Generate polls of recurring series in the background
+    tokio::spawn(run_series_scheduler(db.clone()));
+
     // Initialize App State
     let app_state = AppState::new();
 
+    // Feed live results with votes cast on other instances
+    tokio::spawn(run_change_stream_listener(
+        db.clone(),
+        app_state.live_results.clone(),
+    ));
+
     let app = Router::new()
         .nest("/api/auth", auth_router())
         .nest("/api/polls", poll_router())
+        .nest("/api/templates", template_router())
+        .nest("/api/series", series_router())
+        .nest("/api/presentations", presentation_router())
+        .nest("/api/orgs", organisation_router())
+        .nest("/api/groups", user_group_router())
         .layer(init_cors())
         .layer(init_session())
         .layer(TraceLayer::new_for_http())
         .layer(Extension(db))
-        .layer(Extension(app_state));
+        .layer(Extension(app_state))
+        .layer(Extension(ValidationLimits::from_env()));
 
     let port = std::env::var("PORT").unwrap_or_else(|_| "9000".to_string());
     info!("🚀 Server started successfully at port {}", port);
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port))
         .await
         .unwrap();
-    axum::serve(listener, app).await.unwrap();
+    // Client addresses feed the guest vote fingerprints
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .unwrap();
     Ok(())
 }
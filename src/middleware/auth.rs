@@ -3,7 +3,7 @@ use axum::{
     extract::Request,
     http::{
         header::{AUTHORIZATION, COOKIE},
-        StatusCode,
+        HeaderMap, StatusCode,
     },
     middleware::Next,
     response::Response,
@@ -13,9 +13,23 @@ pub async fn require_auth(
     mut req: Request,
     next: Next,
 ) -> Result<Response, (StatusCode, &'static str)> {
+    let Some(token) = auth_token(req.headers()) else {
+        return Err((StatusCode::UNAUTHORIZED, "Missing authorization"));
+    };
+
+    match verify_token(&token, &std::env::var("JWT_SECRET").unwrap().into_bytes()) {
+        Ok(claims) => {
+            req.extensions_mut().insert(claims);
+            Ok(next.run(req).await)
+        }
+        Err(_) => Err((StatusCode::UNAUTHORIZED, "Invalid token")),
+    }
+}
+
+/// Session token of the request, from the `authToken` cookie or the Bearer token
+pub fn auth_token(headers: &HeaderMap) -> Option<String> {
     // Try to get token from cookie first
-    let token = req
-        .headers()
+    let token = headers
         .get(COOKIE)
         .and_then(|cookie_header| cookie_header.to_str().ok())
         .and_then(|cookie_str| {
@@ -26,28 +40,12 @@ pub async fn require_auth(
         });
 
     // Fallback to Bearer token if cookie not found
-    let token = match token {
-        Some(t) => t.to_string(),
-        None => {
-            // Try Bearer token
-            let auth_header = req
-                .headers()
-                .get(AUTHORIZATION)
-                .and_then(|header| header.to_str().ok())
-                .and_then(|header| header.strip_prefix("Bearer "));
-
-            match auth_header {
-                Some(token) => token.to_string(),
-                None => return Err((StatusCode::UNAUTHORIZED, "Missing authorization")),
-            }
-        }
-    };
-
-    match verify_token(&token, &std::env::var("JWT_SECRET").unwrap().into_bytes()) {
-        Ok(claims) => {
-            req.extensions_mut().insert(claims);
-            Ok(next.run(req).await)
-        }
-        Err(_) => Err((StatusCode::UNAUTHORIZED, "Invalid token")),
+    match token {
+        Some(t) => Some(t.to_string()),
+        None => headers
+            .get(AUTHORIZATION)
+            .and_then(|header| header.to_str().ok())
+            .and_then(|header| header.strip_prefix("Bearer "))
+            .map(str::to_string),
     }
 }
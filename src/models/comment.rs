@@ -0,0 +1,37 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::responses::CommentResponseDTO;
+
+/// Comment in the discussion of a poll, replies point to the comment they answer
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub commentId: String,
+    pub pollId: String,
+    pub parentId: Option<String>, // Unset on top level comments
+    /// References UserId in the main collection
+    pub authorId: String,
+    pub text: String,
+    pub replyCount: i32,
+    pub isDeleted: bool, // Deleted comments keep their place so replies stay in their thread
+    pub createdAt: DateTime,
+    pub editedAt: Option<DateTime>,
+}
+
+impl Comment {
+    /// Deleted comments lose their text and author
+    pub fn to_response_dto(&self) -> CommentResponseDTO {
+        CommentResponseDTO {
+            comment_id: self.commentId.clone(),
+            poll_id: self.pollId.clone(),
+            parent_id: self.parentId.clone(),
+            author_id: (!self.isDeleted).then(|| self.authorId.clone()),
+            text: (!self.isDeleted).then(|| self.text.clone()),
+            reply_count: self.replyCount,
+            is_deleted: self.isDeleted,
+            created_at: self.createdAt.to_string(),
+            edited_at: self.editedAt.map(|at| at.to_string()),
+        }
+    }
+}
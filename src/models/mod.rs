@@ -1,3 +1,11 @@
+pub mod comment;
+pub mod organisation;
 pub mod poll;
+pub mod poll_revision;
+pub mod poll_series;
+pub mod poll_template;
+pub mod presentation;
+pub mod question;
 pub mod registration_state;
 pub mod user;
+pub mod user_group;
@@ -0,0 +1,81 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::responses::{OrganisationMemberDTO, OrganisationResponseDTO};
+
+/// Team of users that can own polls together
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Organisation {
+    pub orgId: String,
+    pub name: String,
+    /// References UserId in the main collection
+    pub createdBy: String,
+    pub joinCode: String, // Redeeming it adds the user as a viewer
+    pub members: Vec<OrganisationMember>,
+    pub createdAt: DateTime,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrganisationMember {
+    pub userId: String,
+    pub role: OrgRole,
+    pub joinedAt: DateTime,
+}
+
+/// Ordered from least to most privileged
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OrgRole {
+    Viewer, // Sees the organisation's polls, private ones included
+    Editor, // Creates polls for the organisation and manages all of them
+    Admin,  // Manages members below owner
+    Owner,
+}
+
+impl Organisation {
+    pub fn role_of(&self, user_id: &str) -> Option<OrgRole> {
+        self.members
+            .iter()
+            .find(|member| member.userId == user_id)
+            .map(|member| member.role)
+    }
+
+    /// Whether the user is a member with at least the given role
+    pub fn has_role(&self, user_id: &str, role: OrgRole) -> bool {
+        self.role_of(user_id).is_some_and(|r| r >= role)
+    }
+
+    pub fn owner_count(&self) -> usize {
+        self.members
+            .iter()
+            .filter(|member| member.role == OrgRole::Owner)
+            .count()
+    }
+
+    /// Members below admin don't get to see the join code
+    pub fn to_response_dto(&self, user_id: &str) -> OrganisationResponseDTO {
+        let role = self.role_of(user_id);
+
+        OrganisationResponseDTO {
+            org_id: self.orgId.clone(),
+            name: self.name.clone(),
+            created_by: self.createdBy.clone(),
+            join_code: role
+                .filter(|r| *r >= OrgRole::Admin)
+                .map(|_| self.joinCode.clone()),
+            role,
+            members: self
+                .members
+                .iter()
+                .map(|member| OrganisationMemberDTO {
+                    user_id: member.userId.clone(),
+                    role: member.role,
+                    joined_at: member.joinedAt.to_string(),
+                })
+                .collect(),
+            created_at: self.createdAt.to_string(),
+        }
+    }
+}
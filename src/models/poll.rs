@@ -1,10 +1,14 @@
+use chrono::Utc;
 use mongodb::bson::DateTime;
 use serde::{Deserialize, Serialize};
 
-use crate::dtos::responses::{PollOptionResponseDTO, PollResponseDTO};
+use crate::{
+    dtos::responses::{PollOptionResponseDTO, PollResponseDTO},
+    models::organisation::OrgRole,
+};
 
 #[allow(non_snake_case)]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Poll {
     pub pollId: String,
     /// References UserId in the main collection
@@ -17,18 +21,201 @@ pub struct Poll {
     pub endDate: DateTime,        //To close the poll
     pub options: Vec<PollOption>, // Embedded options
     pub votedBy: Vec<String>,
+    #[serde(default)]
+    pub seriesId: Option<String>, // Set on instances generated by a recurring series
+    #[serde(default)]
+    pub orgId: Option<String>, // Organisation sharing ownership of the poll
+    #[serde(default)]
+    pub isPrivate: bool, // Hidden from listings, only for the owner and allowedUsers
+    #[serde(default)]
+    pub inviteCode: Option<String>, // Redeeming it adds the user to allowedUsers
+    #[serde(default)]
+    pub allowedUsers: Vec<String>,
+    #[serde(default)]
+    pub joinCode: Option<String>, // Short code for live audiences, removed once the poll closes
+    #[serde(default)]
+    pub collaborators: Vec<PollCollaborator>, // Users granted rights on this poll only
+    #[serde(default)]
+    pub targetGroups: Vec<String>, // When set, only members of these user groups may vote
+    #[serde(default)]
+    pub allowGuests: bool, // Lets unauthenticated devices vote with a guest token
+    #[serde(default)]
+    pub guestFingerprinting: bool, // Also reject guest votes from an already seen IP and user agent
+    #[serde(default)]
+    pub guestFingerprints: Vec<String>, // Hashes of devices that cast a guest vote
+    #[serde(default)]
+    pub resultsVisibility: ResultsVisibility,
+    #[serde(default)]
+    pub shuffleOptions: bool, // Each voter sees the options in an order of their own
+    #[serde(default)]
+    pub passwordHash: Option<String>, // Argon2 PHC string, never sent to clients
+    #[serde(default)]
+    pub passwordChangedAt: Option<DateTime>, // Access tokens issued before this are rejected
+    #[serde(default)]
+    pub peakViewers: i64, // Most live results viewers connected at once
+    #[serde(default)]
+    pub peakViewersAt: Option<DateTime>,
 }
 
 #[allow(non_snake_case)]
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PollOption {
     pub optionName: String,
     pub optionId: String,
     pub votes: i32,
+    #[serde(default)]
+    pub displayOrder: i32, // Position of the option when shown to voters
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollCollaborator {
+    pub userId: String,
+    pub role: CollaboratorRole,
+    pub accepted: bool, // Rights only apply once the invitee accepts
+    pub invitedBy: String,
+    pub invitedAt: DateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CollaboratorRole {
+    CoOwner,
+    Moderator,
+}
+
+/// When vote counts are shown to people who don't manage the poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ResultsVisibility {
+    #[default]
+    Always,
+    AfterVote, // Once the user voted, or the poll is over
+    AfterClose,
+    OwnerOnly,
+}
+
+/// Emoji live viewers can react with, reactions are never stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Reaction {
+    ThumbsUp,  // 👍
+    Heart,     // ❤️
+    Laugh,     // 😂
+    Surprised, // 😮
+    Clap,      // 👏
+    Fire,      // 🔥
+}
+
+/// Things that can be done to a poll besides voting on it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PollAction {
+    Edit,
+    Close,
+    Reset,
+    ManageAccess, // Access list, invite and join codes
+    ViewVoters,
+    ViewResults, // Vote counts regardless of the results visibility
+    #[allow(dead_code)] // Polls don't take write-in options yet
+    ModerateWriteIns,
+    ModerateComments, // Delete anyone's comments
+    ManageCollaborators,
+}
+
+impl CollaboratorRole {
+    pub fn allows(&self, action: PollAction) -> bool {
+        match self {
+            CollaboratorRole::CoOwner => action != PollAction::ManageCollaborators,
+            CollaboratorRole::Moderator => matches!(
+                action,
+                PollAction::ViewVoters
+                    | PollAction::ViewResults
+                    | PollAction::ModerateWriteIns
+                    | PollAction::ModerateComments
+            ),
+        }
+    }
 }
 
 impl Poll {
-    pub fn to_response_dto(self) -> PollResponseDTO {
+    /// Role of the user if they accepted an invitation to collaborate
+    pub fn collaborator_role(&self, user_id: &str) -> Option<CollaboratorRole> {
+        self.collaborators
+            .iter()
+            .find(|c| c.accepted && c.userId == user_id)
+            .map(|c| c.role)
+    }
+
+    /// Whether the user may perform `action` on the poll, given their role in the organisation
+    /// owning it. The creator and organisation editors may do anything, collaborators what
+    /// their role allows.
+    pub fn permits(&self, user_id: &str, org_role: Option<OrgRole>, action: PollAction) -> bool {
+        self.createdBy == user_id
+            || self
+                .collaborator_role(user_id)
+                .is_some_and(|role| role.allows(action))
+            || org_role.is_some_and(|role| role >= OrgRole::Editor)
+    }
+
+    /// Whether the user may see and vote on the poll
+    pub fn is_accessible_by(&self, user_id: &str) -> bool {
+        !self.isPrivate
+            || self.createdBy == user_id
+            || self.allowedUsers.iter().any(|u| u == user_id)
+            || self.collaborator_role(user_id).is_some()
+    }
+
+    /// Whether the results visibility lets the user see vote counts, without looking at
+    /// organisation roles
+    pub fn results_visible_to(&self, user_id: &str) -> bool {
+        let is_over =
+            self.isClosed || self.endDate.timestamp_millis() <= Utc::now().timestamp_millis();
+
+        self.createdBy == user_id
+            || self
+                .collaborator_role(user_id)
+                .is_some_and(|role| role.allows(PollAction::ViewResults))
+            || match self.resultsVisibility {
+                ResultsVisibility::Always => true,
+                ResultsVisibility::AfterVote => {
+                    is_over || self.votedBy.iter().any(|u| u == user_id)
+                }
+                ResultsVisibility::AfterClose => is_over,
+                ResultsVisibility::OwnerOnly => false,
+            }
+    }
+
+    /// Response for a user with the given role in the organisation owning the poll. Vote counts
+    /// are left out unless they may see them, the join code unless they manage access to the
+    /// poll and the voters unless they may view them.
+    pub fn into_response_dto_for(
+        self,
+        user_id: &str,
+        org_role: Option<OrgRole>,
+    ) -> PollResponseDTO {
+        let visible = self.results_visible_to(user_id)
+            || self.permits(user_id, org_role, PollAction::ViewResults);
+        let manages_access = self.permits(user_id, org_role, PollAction::ManageAccess);
+        let views_voters = self.permits(user_id, org_role, PollAction::ViewVoters);
+        let mut dto = self.to_response_dto();
+
+        if !manages_access {
+            dto = dto.without_join_code();
+        }
+        if !views_voters {
+            dto = dto.without_voters();
+        }
+        if visible {
+            dto
+        } else {
+            dto.without_results()
+        }
+    }
+
+    pub fn to_response_dto(mut self) -> PollResponseDTO {
+        // Stable sort keeps insertion order for polls created before displayOrder existed
+        self.options.sort_by_key(|opt| opt.displayOrder);
+
         PollResponseDTO {
             poll_id: self.pollId,
             created_by: self.createdBy,
@@ -39,13 +226,25 @@ impl Poll {
             start_date: self.startDate.to_string(),
             end_date: self.endDate.to_string(),
             voted_by: self.votedBy,
+            series_id: self.seriesId,
+            org_id: self.orgId,
+            target_groups: self.targetGroups,
+            allow_guests: self.allowGuests,
+            guest_fingerprinting: self.guestFingerprinting,
+            results_visibility: self.resultsVisibility,
+            results_hidden: false,
+            shuffle_options: self.shuffleOptions,
+            has_password: self.passwordHash.is_some(),
+            is_private: self.isPrivate,
+            join_code: self.joinCode,
             options: self
                 .options
                 .into_iter()
                 .map(|opt| PollOptionResponseDTO {
                     option_id: opt.optionId,
                     option_name: opt.optionName,
-                    votes: opt.votes,
+                    votes: Some(opt.votes),
+                    display_order: opt.displayOrder,
                 })
                 .collect(),
         }
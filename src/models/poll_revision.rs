@@ -0,0 +1,199 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::{
+    dtos::responses::{FieldChangeDTO, PollResponseDTO, PollRevisionResponseDTO},
+    models::poll::ResultsVisibility,
+};
+
+/// One entry of a poll's edit history.
+/// Revision 1 is the poll as it was created, every later revision is an edit.
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollRevision {
+    pub pollId: String,
+    pub revision: i32,
+    /// References UserId in the main collection
+    pub editedBy: String,
+    pub editedAt: DateTime,
+    pub changes: Vec<FieldChange>, // Changes compared to the previous revision
+    pub snapshot: PollSnapshot,    // Editable state of the poll after this revision
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PollSnapshot {
+    pub name: String,
+    pub isMulti: bool,
+    pub startDate: String,
+    pub endDate: String,
+    pub options: Vec<SnapshotOption>, // In display order
+    #[serde(default)]
+    pub isPrivate: bool,
+    #[serde(default)]
+    pub targetGroups: Vec<String>,
+    #[serde(default)]
+    pub allowGuests: bool,
+    #[serde(default)]
+    pub guestFingerprinting: bool,
+    #[serde(default)]
+    pub resultsVisibility: ResultsVisibility,
+    #[serde(default)]
+    pub shuffleOptions: bool,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SnapshotOption {
+    pub optionId: String,
+    pub optionName: String,
+}
+
+impl PollSnapshot {
+    pub fn from_response(poll: &PollResponseDTO) -> Self {
+        PollSnapshot {
+            name: poll.name.clone(),
+            isMulti: poll.is_multi,
+            startDate: poll.start_date.clone(),
+            endDate: poll.end_date.clone(),
+            options: poll
+                .options
+                .iter()
+                .map(|opt| SnapshotOption {
+                    optionId: opt.option_id.clone(),
+                    optionName: opt.option_name.clone(),
+                })
+                .collect(),
+            isPrivate: poll.is_private,
+            targetGroups: poll.target_groups.clone(),
+            allowGuests: poll.allow_guests,
+            guestFingerprinting: poll.guest_fingerprinting,
+            resultsVisibility: poll.results_visibility,
+            shuffleOptions: poll.shuffle_options,
+        }
+    }
+
+    /// Lists what changed going from `self` to `other`.
+    /// Options are compared by id, so a rename shows up as `options.<optionId>`, an added option
+    /// has a `null` before value and a removed one a `null` after value.
+    pub fn diff(&self, other: &PollSnapshot) -> Vec<FieldChange> {
+        let mut changes = Vec::new();
+
+        let mut compare = |field: &str, before: Value, after: Value| {
+            if before != after {
+                changes.push(FieldChange {
+                    field: field.to_string(),
+                    before,
+                    after,
+                });
+            }
+        };
+
+        compare("name", self.name.clone().into(), other.name.clone().into());
+        compare("isMulti", self.isMulti.into(), other.isMulti.into());
+        compare("isPrivate", self.isPrivate.into(), other.isPrivate.into());
+        compare(
+            "targetGroups",
+            self.targetGroups.clone().into(),
+            other.targetGroups.clone().into(),
+        );
+        compare(
+            "allowGuests",
+            self.allowGuests.into(),
+            other.allowGuests.into(),
+        );
+        compare(
+            "guestFingerprinting",
+            self.guestFingerprinting.into(),
+            other.guestFingerprinting.into(),
+        );
+        compare(
+            "resultsVisibility",
+            json!(self.resultsVisibility),
+            json!(other.resultsVisibility),
+        );
+        compare(
+            "shuffleOptions",
+            self.shuffleOptions.into(),
+            other.shuffleOptions.into(),
+        );
+        compare(
+            "startDate",
+            self.startDate.clone().into(),
+            other.startDate.clone().into(),
+        );
+        compare(
+            "endDate",
+            self.endDate.clone().into(),
+            other.endDate.clone().into(),
+        );
+
+        let option_name = |options: &[SnapshotOption], option_id: &str| -> Value {
+            options
+                .iter()
+                .find(|opt| opt.optionId == option_id)
+                .map(|opt| Value::from(opt.optionName.clone()))
+                .unwrap_or(Value::Null)
+        };
+
+        let mut option_ids: Vec<&str> = self.options.iter().map(|o| o.optionId.as_str()).collect();
+        for opt in &other.options {
+            if !option_ids.contains(&opt.optionId.as_str()) {
+                option_ids.push(&opt.optionId);
+            }
+        }
+
+        for option_id in option_ids {
+            compare(
+                &format!("options.{}", option_id),
+                option_name(&self.options, option_id),
+                option_name(&other.options, option_id),
+            );
+        }
+
+        // Adding or removing options is already listed above, only report actual reordering
+        let shared_order = |options: &[SnapshotOption], others: &[SnapshotOption]| -> Vec<String> {
+            options
+                .iter()
+                .filter(|opt| others.iter().any(|o| o.optionId == opt.optionId))
+                .map(|opt| opt.optionId.clone())
+                .collect()
+        };
+        let before_order = shared_order(&self.options, &other.options);
+        let after_order = shared_order(&other.options, &self.options);
+        if before_order != after_order {
+            compare("optionOrder", before_order.into(), after_order.into());
+        }
+
+        changes
+    }
+}
+
+impl FieldChange {
+    pub fn to_response_dto(&self) -> FieldChangeDTO {
+        FieldChangeDTO {
+            field: self.field.clone(),
+            before: self.before.clone(),
+            after: self.after.clone(),
+        }
+    }
+}
+
+impl PollRevision {
+    pub fn to_response_dto(&self) -> PollRevisionResponseDTO {
+        PollRevisionResponseDTO {
+            revision: self.revision,
+            edited_by: self.editedBy.clone(),
+            edited_at: self.editedAt.to_string(),
+            changes: self.changes.iter().map(|c| c.to_response_dto()).collect(),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use chrono::Weekday;
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::responses::{
+    OptionTrendDTO, PollResponseDTO, PollSeriesResponseDTO, SeriesInstanceDTO, SeriesTrendsDTO,
+};
+
+/// A recurring poll, new instances are generated from it by the series scheduler
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollSeries {
+    pub seriesId: String,
+    /// References UserId in the main collection
+    pub createdBy: String,
+    pub name: String, // May contain a `{{date}}` placeholder, filled with the instance's local date
+    pub isMulti: bool,
+    pub options: Vec<String>,
+    pub recurrence: RecurrenceRule,
+    pub nextRunAt: DateTime, // Start of the next instance to generate
+    pub isActive: bool,
+    pub createdAt: DateTime,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecurrenceRule {
+    #[serde(flatten)]
+    pub frequency: Frequency,
+    pub startTime: String, // Local "HH:MM" at which every instance opens
+    pub durationMinutes: i64,
+    pub timezone: String, // IANA name, e.g. "Europe/Berlin"
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "frequency", rename_all = "camelCase")]
+pub enum Frequency {
+    Daily,
+    Weekly {
+        weekdays: Vec<Weekday>,
+    },
+    /// Falls back to the last day of shorter months
+    Monthly {
+        dayOfMonth: u32,
+    },
+}
+
+impl PollSeries {
+    pub fn to_response_dto(&self) -> PollSeriesResponseDTO {
+        PollSeriesResponseDTO {
+            series_id: self.seriesId.clone(),
+            created_by: self.createdBy.clone(),
+            name: self.name.clone(),
+            is_multi: self.isMulti,
+            options: self.options.clone(),
+            recurrence: self.recurrence.clone(),
+            next_run_at: self.nextRunAt.to_string(),
+            is_active: self.isActive,
+        }
+    }
+
+    /// Lines up the results of the generated instances, matching options by name so
+    /// renamed or added options show up as their own trend line
+    pub fn trends(&self, instances: Vec<PollResponseDTO>) -> SeriesTrendsDTO {
+        let mut option_names: Vec<String> = Vec::new();
+        for poll in &instances {
+            for opt in &poll.options {
+                if !option_names.contains(&opt.option_name) {
+                    option_names.push(opt.option_name.clone());
+                }
+            }
+        }
+
+        let totals: Vec<i32> = instances
+            .iter()
+            .map(|poll| poll.options.iter().filter_map(|opt| opt.votes).sum())
+            .collect();
+
+        let option_trends = option_names
+            .into_iter()
+            .map(|option_name| {
+                let votes: Vec<i32> = instances
+                    .iter()
+                    .map(|poll| {
+                        poll.options
+                            .iter()
+                            .filter(|opt| opt.option_name == option_name)
+                            .filter_map(|opt| opt.votes)
+                            .sum()
+                    })
+                    .collect();
+                let percentages = votes
+                    .iter()
+                    .zip(&totals)
+                    .map(|(votes, total)| match total {
+                        0 => 0.0,
+                        total => f64::from(*votes) * 100.0 / f64::from(*total),
+                    })
+                    .collect();
+
+                OptionTrendDTO {
+                    option_name,
+                    votes,
+                    percentages,
+                }
+            })
+            .collect();
+
+        SeriesTrendsDTO {
+            series_id: self.seriesId.clone(),
+            instances: instances
+                .into_iter()
+                .zip(totals)
+                .map(|(poll, total_votes)| SeriesInstanceDTO {
+                    poll_id: poll.poll_id,
+                    name: poll.name,
+                    start_date: poll.start_date,
+                    end_date: poll.end_date,
+                    is_closed: poll.is_closed,
+                    total_votes,
+                })
+                .collect(),
+            option_trends,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    dtos::{
+        requests::{CreatePollDTO, InstantiateTemplateDTO},
+        responses::PollTemplateResponseDTO,
+    },
+    error::{AppError, PollsError},
+    models::poll::ResultsVisibility,
+    utils::template::{placeholders, render},
+};
+
+/// Reusable poll definition, its name and options may contain `{{param}}` placeholders
+/// that are filled in when a poll is created from it.
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PollTemplate {
+    pub templateId: String,
+    /// References UserId in the main collection
+    pub createdBy: String,
+    pub title: String, // Label shown in the template library
+    pub name: String,
+    pub isMulti: bool,
+    pub options: Vec<String>,
+    pub createdAt: DateTime,
+}
+
+impl PollTemplate {
+    pub fn to_response_dto(&self) -> PollTemplateResponseDTO {
+        PollTemplateResponseDTO {
+            template_id: self.templateId.clone(),
+            created_by: self.createdBy.clone(),
+            title: self.title.clone(),
+            name: self.name.clone(),
+            is_multi: self.isMulti,
+            options: self.options.clone(),
+            parameters: placeholders(
+                std::iter::once(self.name.as_str()).chain(self.options.iter().map(String::as_str)),
+            ),
+            created_at: self.createdAt.to_string(),
+        }
+    }
+
+    /// Fills in the placeholders to build the poll to create
+    pub fn instantiate(
+        &self,
+        dto: InstantiateTemplateDTO,
+        created_by: String,
+    ) -> Result<CreatePollDTO, AppError> {
+        let render = |text: &str| {
+            render(text, &dto.params).map_err(|param| {
+                AppError::Poll(PollsError::InvalidConfiguration(format!(
+                    "Missing value for template parameter '{}'",
+                    param
+                )))
+            })
+        };
+
+        Ok(CreatePollDTO {
+            name: render(&self.name)?,
+            isMulti: self.isMulti,
+            startDate: dto.startDate,
+            endDate: dto.endDate,
+            createdBy: created_by,
+            options: self
+                .options
+                .iter()
+                .map(|option| render(option))
+                .collect::<Result<Vec<String>, AppError>>()?,
+            isPrivate: false,
+            orgId: None,
+            targetGroups: vec![],
+            allowGuests: false,
+            guestFingerprinting: false,
+            resultsVisibility: ResultsVisibility::default(),
+            shuffleOptions: false,
+            seriesId: None,
+        })
+    }
+}
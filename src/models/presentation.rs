@@ -0,0 +1,61 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::responses::PresentationResponseDTO;
+
+/// A live session running through a sequence of polls, driven by its presenter
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Presentation {
+    pub presentationId: String,
+    /// References UserId in the main collection
+    pub createdBy: String,
+    pub title: String,
+    pub pollIds: Vec<String>, // In the order they're presented
+    #[serde(default)]
+    pub moderators: Vec<String>, // Moderate the Q&A along with the presenter
+    pub position: i32,        // Index of the current poll in pollIds
+    pub phase: PresentationPhase,
+    pub createdAt: DateTime,
+}
+
+/// What the audience sees of the current poll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PresentationPhase {
+    Waiting, // Not started yet, no poll is shown
+    Voting,
+    Locked,   // The poll is shown but doesn't take votes
+    Revealed, // Votes are locked and the results shown
+    Ended,
+}
+
+impl PresentationPhase {
+    /// Whether a poll is currently shown to the audience
+    pub fn is_running(&self) -> bool {
+        !matches!(self, PresentationPhase::Waiting | PresentationPhase::Ended)
+    }
+}
+
+impl Presentation {
+    pub fn current_poll_id(&self) -> Option<&str> {
+        self.pollIds.get(self.position as usize).map(String::as_str)
+    }
+
+    pub fn can_moderate(&self, user_id: &str) -> bool {
+        self.createdBy == user_id || self.moderators.iter().any(|m| m == user_id)
+    }
+
+    pub fn to_response_dto(&self) -> PresentationResponseDTO {
+        PresentationResponseDTO {
+            presentation_id: self.presentationId.clone(),
+            created_by: self.createdBy.clone(),
+            title: self.title.clone(),
+            poll_ids: self.pollIds.clone(),
+            moderators: self.moderators.clone(),
+            position: self.position,
+            phase: self.phase,
+            created_at: self.createdAt.to_string(),
+        }
+    }
+}
@@ -0,0 +1,105 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::{dtos::responses::QuestionResponseDTO, utils::votes::has_voted};
+
+/// A question the audience of a presentation asked, ranked by upvotes
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Question {
+    pub questionId: String,
+    pub presentationId: String,
+    pub text: String,
+    /// References UserId in the main collection
+    pub askedBy: String,
+    pub status: QuestionStatus,
+    pub upvotes: i32,
+    pub upvotedBy: Vec<String>, // One upvote per user, like votes on polls
+    pub createdAt: DateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum QuestionStatus {
+    Pending, // Waiting for a moderator, only its author sees it
+    Approved,
+    Hidden,
+    Answered,
+}
+
+impl QuestionStatus {
+    /// Whether the whole audience sees questions in this status
+    pub fn is_public(&self) -> bool {
+        matches!(self, QuestionStatus::Approved | QuestionStatus::Answered)
+    }
+}
+
+impl Question {
+    /// Authors are only shown to moderators and to the authors themselves
+    pub fn to_response_dto(&self, viewer: Option<&str>, can_moderate: bool) -> QuestionResponseDTO {
+        let sees_author = can_moderate || viewer == Some(self.askedBy.as_str());
+        QuestionResponseDTO {
+            question_id: self.questionId.clone(),
+            presentation_id: self.presentationId.clone(),
+            text: self.text.clone(),
+            asked_by: sees_author.then(|| self.askedBy.clone()),
+            status: self.status,
+            upvotes: self.upvotes,
+            upvoted: viewer.is_some_and(|user_id| has_voted(&self.upvotedBy, user_id)),
+            created_at: self.createdAt.to_string(),
+        }
+    }
+}
+
+/// Open questions first, most upvoted on top and oldest first on ties
+pub fn rank(questions: &mut [Question]) {
+    questions.sort_by(|a, b| {
+        (a.status == QuestionStatus::Answered)
+            .cmp(&(b.status == QuestionStatus::Answered))
+            .then(b.upvotes.cmp(&a.upvotes))
+            .then(a.createdAt.cmp(&b.createdAt))
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn question() -> Question {
+        Question {
+            questionId: String::from("question"),
+            presentationId: String::from("presentation"),
+            text: String::from("Why?"),
+            askedBy: String::from("author"),
+            status: QuestionStatus::Approved,
+            upvotes: 0,
+            upvotedBy: vec![],
+            createdAt: DateTime::now(),
+        }
+    }
+
+    #[test]
+    fn authors_are_only_shown_to_moderators_and_themselves() {
+        let question = question();
+
+        assert_eq!(question.to_response_dto(None, false).asked_by, None);
+        assert_eq!(
+            question.to_response_dto(Some("viewer"), false).asked_by,
+            None
+        );
+        assert_eq!(
+            question
+                .to_response_dto(Some("author"), false)
+                .asked_by
+                .as_deref(),
+            Some("author")
+        );
+        assert_eq!(
+            question
+                .to_response_dto(Some("moderator"), true)
+                .asked_by
+                .as_deref(),
+            Some("author")
+        );
+    }
+}
@@ -0,0 +1,28 @@
+use mongodb::bson::DateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::dtos::responses::UserGroupResponseDTO;
+
+/// Cohort of users that polls can restrict voting to
+#[allow(non_snake_case)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserGroup {
+    pub groupId: String,
+    pub name: String,
+    /// References UserId in the main collection, only they manage the group
+    pub createdBy: String,
+    pub members: Vec<String>,
+    pub createdAt: DateTime,
+}
+
+impl UserGroup {
+    pub fn to_response_dto(&self) -> UserGroupResponseDTO {
+        UserGroupResponseDTO {
+            group_id: self.groupId.clone(),
+            name: self.name.clone(),
+            created_by: self.createdBy.clone(),
+            members: self.members.clone(),
+            created_at: self.createdAt.to_string(),
+        }
+    }
+}
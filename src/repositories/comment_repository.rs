@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, PollsError},
+    models::comment::Comment,
+};
+
+#[derive(Clone)]
+pub struct CommentRepository {
+    comments: Collection<Comment>,
+}
+
+impl CommentRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let comments = db.collection::<Comment>("comments");
+        Self { comments }
+    }
+
+    /// Replies count towards their parent, which must be a comment of the same poll
+    pub async fn create_comment(
+        &self,
+        poll_id: &str,
+        parent_id: Option<String>,
+        author_id: String,
+        text: String,
+    ) -> Result<Comment, AppError> {
+        if let Some(parent_id) = &parent_id {
+            let parent = self.find_comment(poll_id, parent_id).await?;
+            if parent.isDeleted {
+                return Err(AppError::Poll(PollsError::CommentNotFound));
+            }
+        }
+
+        let comment = Comment {
+            commentId: Uuid::new_v4().to_string(),
+            pollId: poll_id.to_string(),
+            parentId: parent_id,
+            authorId: author_id,
+            text,
+            replyCount: 0,
+            isDeleted: false,
+            createdAt: BsonDateTime::now(),
+            editedAt: None,
+        };
+
+        info!("Inserting new comment to db {:#?}", comment.commentId);
+
+        self.comments
+            .insert_one(&comment)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if let Some(parent_id) = &comment.parentId {
+            self.comments
+                .update_one(
+                    doc! { "commentId": parent_id },
+                    doc! { "$inc": { "replyCount": 1 } },
+                )
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(comment)
+    }
+
+    pub async fn find_comment(&self, poll_id: &str, comment_id: &str) -> Result<Comment, AppError> {
+        self.comments
+            .find_one(doc! { "pollId": poll_id, "commentId": comment_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::CommentNotFound))
+    }
+
+    /// A page of the top level comments of the poll, newest first, or of the replies to
+    /// `parent_id`, oldest first. Also returns how many there are in total.
+    pub async fn get_comments(
+        &self,
+        poll_id: &str,
+        parent_id: Option<&str>,
+        page: u64,
+        limit: u64,
+    ) -> Result<(Vec<Comment>, u64), AppError> {
+        let filter = doc! { "pollId": poll_id, "parentId": parent_id };
+        let order = if parent_id.is_some() { 1 } else { -1 };
+        // Pages past the end are empty, however far past
+        let skip = page.saturating_sub(1).saturating_mul(limit);
+
+        let total = self
+            .comments
+            .count_documents(filter.clone())
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let comments = self
+            .comments
+            .find(filter)
+            .sort(doc! { "createdAt": order })
+            .skip(skip)
+            .limit(limit as i64)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Comment>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok((comments, total))
+    }
+
+    pub async fn update_text(&self, comment_id: &str, text: String) -> Result<(), AppError> {
+        self.comments
+            .update_one(
+                doc! { "commentId": comment_id },
+                doc! { "$set": { "text": text, "editedAt": BsonDateTime::now() } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    /// Clears the text but keeps the comment, so its replies stay reachable
+    pub async fn delete_comment(&self, comment_id: &str) -> Result<(), AppError> {
+        self.comments
+            .update_one(
+                doc! { "commentId": comment_id },
+                doc! { "$set": { "isDeleted": true, "text": "" } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
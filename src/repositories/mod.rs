@@ -1,3 +1,11 @@
+pub mod comment_repository;
+pub mod organisation_repository;
 pub mod poll_repository;
+pub mod poll_revision_repository;
+pub mod poll_series_repository;
+pub mod poll_template_repository;
+pub mod presentation_repository;
+pub mod question_repository;
 pub mod registration_state_repository;
+pub mod user_group_repository;
 pub mod user_repository;
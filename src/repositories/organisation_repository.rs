@@ -0,0 +1,240 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, to_bson, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, PollsError},
+    models::organisation::{OrgRole, Organisation, OrganisationMember},
+    utils::codes::generate_invite_code,
+};
+
+#[derive(Clone)]
+pub struct OrganisationRepository {
+    organisations: Collection<Organisation>,
+}
+
+impl OrganisationRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let organisations = db.collection::<Organisation>("organisations");
+        Self { organisations }
+    }
+
+    /// The creator becomes the first owner
+    pub async fn create_organisation(
+        &self,
+        name: String,
+        created_by: String,
+    ) -> Result<Organisation, AppError> {
+        let now = BsonDateTime::now();
+        let organisation = Organisation {
+            orgId: Uuid::new_v4().to_string(),
+            name,
+            createdBy: created_by.clone(),
+            joinCode: generate_invite_code(),
+            members: vec![OrganisationMember {
+                userId: created_by,
+                role: OrgRole::Owner,
+                joinedAt: now,
+            }],
+            createdAt: now,
+        };
+
+        info!("Inserting new organisation to db {:#?}", organisation.orgId);
+
+        self.organisations
+            .insert_one(&organisation)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(organisation)
+    }
+
+    pub async fn get_organisations_of_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<Organisation>, AppError> {
+        self.organisations
+            .find(doc! { "members.userId": user_id })
+            .sort(doc! { "createdAt": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Organisation>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    pub async fn find_organisation(&self, org_id: &str) -> Result<Organisation, AppError> {
+        self.organisations
+            .find_one(doc! { "orgId": org_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::OrganisationNotFound))
+    }
+
+    /// Fetches an organisation, failing unless the user holds at least `role` in it
+    pub async fn get_organisation_of_member(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        role: OrgRole,
+    ) -> Result<Organisation, AppError> {
+        let organisation = self.find_organisation(org_id).await?;
+
+        match organisation.role_of(user_id) {
+            None => Err(AppError::Poll(PollsError::NotOrganisationMember)),
+            Some(r) if r < role => Err(AppError::Poll(PollsError::InsufficientOrgRole)),
+            Some(_) => Ok(organisation),
+        }
+    }
+
+    /// Adds the user as a viewer, joining twice keeps the existing role
+    pub async fn join_organisation(
+        &self,
+        join_code: &str,
+        user_id: &str,
+    ) -> Result<Organisation, AppError> {
+        let member = OrganisationMember {
+            userId: user_id.to_string(),
+            role: OrgRole::Viewer,
+            joinedAt: BsonDateTime::now(),
+        };
+        let member = to_bson(&member).map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        self.organisations
+            .update_one(
+                doc! { "joinCode": join_code, "members.userId": { "$ne": user_id } },
+                doc! { "$push": { "members": member } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let organisation = self
+            .organisations
+            .find_one(doc! { "joinCode": join_code })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::InvalidInviteCode))?;
+
+        info!(
+            "User {} joined organisation {}",
+            user_id, organisation.orgId
+        );
+
+        Ok(organisation)
+    }
+
+    /// Admins manage members below owner, only owners can grant or take away ownership
+    pub async fn update_member_role(
+        &self,
+        org_id: &str,
+        acting_user: &str,
+        member_id: &str,
+        role: OrgRole,
+    ) -> Result<Organisation, AppError> {
+        let organisation = self
+            .get_organisation_of_member(org_id, acting_user, OrgRole::Admin)
+            .await?;
+        let current = organisation
+            .role_of(member_id)
+            .ok_or(AppError::Poll(PollsError::NotOrganisationMember))?;
+
+        Self::check_can_manage(&organisation, acting_user, current.max(role))?;
+        if current == OrgRole::Owner && role != OrgRole::Owner && organisation.owner_count() == 1 {
+            return Err(AppError::Poll(PollsError::LastOrganisationOwner));
+        }
+
+        let role = to_bson(&role).map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        self.organisations
+            .update_one(
+                doc! { "orgId": org_id, "members.userId": member_id },
+                doc! { "$set": { "members.$.role": role } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        self.find_organisation(org_id).await
+    }
+
+    /// Members may always leave, removing others follows the same rules as role changes
+    pub async fn remove_member(
+        &self,
+        org_id: &str,
+        acting_user: &str,
+        member_id: &str,
+    ) -> Result<(), AppError> {
+        let organisation = self.find_organisation(org_id).await?;
+        let current = organisation
+            .role_of(member_id)
+            .ok_or(AppError::Poll(PollsError::NotOrganisationMember))?;
+
+        if acting_user != member_id {
+            Self::check_can_manage(&organisation, acting_user, current)?;
+        }
+        if current == OrgRole::Owner && organisation.owner_count() == 1 {
+            return Err(AppError::Poll(PollsError::LastOrganisationOwner));
+        }
+
+        self.organisations
+            .update_one(
+                doc! { "orgId": org_id },
+                doc! { "$pull": { "members": { "userId": member_id } } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        info!("User {} removed from organisation {}", member_id, org_id);
+
+        Ok(())
+    }
+
+    pub async fn rotate_join_code(&self, org_id: &str) -> Result<Organisation, AppError> {
+        self.organisations
+            .update_one(
+                doc! { "orgId": org_id },
+                doc! { "$set": { "joinCode": generate_invite_code() } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        self.find_organisation(org_id).await
+    }
+
+    pub async fn delete_organisation(&self, org_id: &str) -> Result<(), AppError> {
+        let delete_result = self
+            .organisations
+            .delete_one(doc! { "orgId": org_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if delete_result.deleted_count == 0 {
+            return Err(AppError::Poll(PollsError::OrganisationNotFound));
+        }
+
+        Ok(())
+    }
+
+    // Admins can act on members up to admin, owners on anyone
+    fn check_can_manage(
+        organisation: &Organisation,
+        acting_user: &str,
+        target_role: OrgRole,
+    ) -> Result<(), AppError> {
+        let required = if target_role == OrgRole::Owner {
+            OrgRole::Owner
+        } else {
+            OrgRole::Admin
+        };
+
+        if !organisation.has_role(acting_user, required) {
+            return Err(AppError::Poll(PollsError::InsufficientOrgRole));
+        }
+
+        Ok(())
+    }
+}
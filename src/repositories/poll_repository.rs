@@ -1,33 +1,66 @@
 #![allow(dead_code)]
-use std::sync::Arc;
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
 use crate::{
+    config::db::is_duplicate_key,
     dtos::{
-        requests::{CreatePollDTO, UpdatePollDTO, UpdatePollReq},
-        responses::PollResponseDTO,
+        requests::{
+            ClonePollDTO, CreatePollDTO, RemovedVotesStrategy, UpdatePollOptionDTO, UpdatePollReq,
+        },
+        responses::{
+            CollaboratorInviteDTO, JoinCodeResponseDTO, PollAccessResponseDTO, PollAnalyticsDTO,
+            PollCollaboratorDTO, PollResponseDTO,
+        },
     },
     error::{AppError, PollsError},
-    models::poll::{Poll, PollOption},
+    models::{
+        organisation::{OrgRole, Organisation},
+        poll::{CollaboratorRole, Poll, PollAction, PollCollaborator, PollOption},
+        user_group::UserGroup,
+    },
+    utils::{
+        codes::{generate_invite_code, generate_join_code, normalize_join_code},
+        votes::has_voted,
+    },
 };
 use futures::TryStreamExt;
-use mongodb::{bson::DateTime as BsonDateTime, Collection};
-use tracing::info;
+use mongodb::{bson::DateTime as BsonDateTime, options::IndexOptions, Collection, IndexModel};
+use tracing::{debug, info};
 use uuid::Uuid;
 
+// Tries at finding a join code nobody else takes first. The code space is large, running
+// out of attempts means something else is wrong.
+const JOIN_CODE_ATTEMPTS: usize = 10;
+
 #[derive(Clone)]
 pub struct PollRepository {
     polls: Collection<Poll>,
+    organisations: Collection<Organisation>,
+    groups: Collection<UserGroup>,
 }
 
 impl PollRepository {
     pub fn new(db: Arc<mongodb::Database>) -> Self {
         let polls = db.collection::<Poll>("polls");
-        Self { polls }
+        let organisations = db.collection::<Organisation>("organisations");
+        let groups = db.collection::<UserGroup>("user_groups");
+        Self {
+            polls,
+            organisations,
+            groups,
+        }
     }
 
-    pub async fn update_poll(&self, poll_id: String, poll: UpdatePollReq) -> Result<(), AppError> {
-        // Add debug logging
-        println!(
+    /// Returns the strategy applied to the votes of removed options, if any had some
+    pub async fn update_poll(
+        &self,
+        poll_id: String,
+        poll: UpdatePollReq,
+    ) -> Result<Option<RemovedVotesStrategy>, AppError> {
+        debug!(
             "Updating poll {} with dates: start={}, end={}",
             poll_id,
             poll.startDate.to_rfc3339(),
@@ -35,38 +68,175 @@ impl PollRepository {
         );
 
         // First check if poll exists and is not closed
-        let _existing_poll = self
-            .get_poll_by_id(poll_id.clone())
-            .await?
-            .ok_or(AppError::Poll(PollsError::PollNotFound))?;
+        let existing_poll = self.find_poll(&poll_id).await?;
 
-        // Add debug logging for MongoDB update
-        let update_doc = mongodb::bson::doc! {
-            "$set": {
-                "name": &poll.name,
-                "isMulti": poll.isMulti,
-                "startDate": BsonDateTime::from_millis(poll.startDate.timestamp_millis()),
-                "endDate": BsonDateTime::from_millis(poll.endDate.timestamp_millis())
-            }
+        let mut filter = mongodb::bson::doc! { "pollId": &poll_id };
+        let mut set_doc = mongodb::bson::doc! {
+            "name": &poll.name,
+            "isMulti": poll.isMulti,
+            "startDate": BsonDateTime::from_millis(poll.startDate.timestamp_millis()),
+            "endDate": BsonDateTime::from_millis(poll.endDate.timestamp_millis())
         };
 
-        println!("MongoDB update document: {:?}", update_doc);
+        if let Some(target_groups) = &poll.targetGroups {
+            set_doc.insert("targetGroups", target_groups);
+        }
+
+        if let Some(allow_guests) = poll.allowGuests {
+            set_doc.insert("allowGuests", allow_guests);
+        }
+
+        if let Some(guest_fingerprinting) = poll.guestFingerprinting {
+            set_doc.insert("guestFingerprinting", guest_fingerprinting);
+        }
+
+        if let Some(results_visibility) = poll.resultsVisibility {
+            set_doc.insert(
+                "resultsVisibility",
+                mongodb::bson::to_bson(&results_visibility)
+                    .map_err(|e| AppError::DatabaseError(e.to_string()))?,
+            );
+        }
+
+        if let Some(shuffle_options) = poll.shuffleOptions {
+            set_doc.insert("shuffleOptions", shuffle_options);
+        }
+
+        if let Some(is_private) = poll.isPrivate {
+            set_doc.insert("isPrivate", is_private);
+            if is_private && existing_poll.inviteCode.is_none() {
+                set_doc.insert("inviteCode", generate_invite_code());
+            }
+        }
+
+        // Option edits are only written if no vote landed meanwhile
+        let edits_options = poll.options.is_some();
+        let mut applied_strategy = None;
+        if let Some(options) = poll.options {
+            if existing_poll.isClosed {
+                return Err(AppError::Poll(PollsError::CannotModifyClosed));
+            }
+
+            let (new_options, removed_votes) = Self::apply_option_edits(
+                &existing_poll.options,
+                options,
+                poll.removedVotes,
+                existing_poll.isMulti,
+            )?;
+
+            // Only write if no vote landed since we read the poll, otherwise it would be lost
+            filter.insert(
+                "votedBy",
+                mongodb::bson::doc! { "$size": existing_poll.votedBy.len() as i64 },
+            );
+            set_doc.insert(
+                "options",
+                mongodb::bson::to_bson(&new_options)
+                    .map_err(|e| AppError::DatabaseError(e.to_string()))?,
+            );
+            if matches!(removed_votes, Some(RemovedVotesStrategy::Reset)) {
+                set_doc.insert("votedBy", mongodb::bson::Bson::Array(vec![]));
+            }
+            applied_strategy = removed_votes;
+        }
+
+        let update_doc = mongodb::bson::doc! { "$set": set_doc };
+
+        debug!("MongoDB update document: {:?}", update_doc);
 
         let update_result = self
             .polls
-            .find_one_and_update(mongodb::bson::doc! { "pollId": poll_id }, update_doc)
+            .find_one_and_update(filter, update_doc)
             .await
             .map_err(|e| AppError::DatabaseError(e.to_string()))?;
 
         match update_result {
             Some(updated_poll) => {
-                println!("Updated document: {:?}", updated_poll);
-                Ok(())
+                debug!("Updated document: {:?}", updated_poll);
+                Ok(applied_strategy)
             }
+            None if edits_options => Err(AppError::Poll(PollsError::UpdateFailed(
+                "Poll received votes while being edited, please retry".to_string(),
+            ))),
+            // Deleted since it was read
             None => Err(AppError::Poll(PollsError::PollNotFound)),
         }
     }
 
+    /// Builds the new option list from the requested one.
+    /// Kept options retain their votes, options missing from the request are removed and their
+    /// votes are either migrated or everything is reset, depending on the chosen strategy.
+    /// Returns the new options and the strategy applied, if removed options had votes.
+    /// Multi-select polls can't migrate, a voter who picked both a removed option and the
+    /// target would be counted twice on it.
+    fn apply_option_edits(
+        existing: &[PollOption],
+        requested: Vec<UpdatePollOptionDTO>,
+        removed_votes: Option<RemovedVotesStrategy>,
+        is_multi: bool,
+    ) -> Result<(Vec<PollOption>, Option<RemovedVotesStrategy>), AppError> {
+        let mut seen_ids = HashSet::new();
+        let mut new_options = Vec::with_capacity(requested.len());
+
+        for (index, option) in requested.into_iter().enumerate() {
+            let (option_id, votes) = match option.optionId {
+                Some(option_id) => {
+                    let current = existing
+                        .iter()
+                        .find(|opt| opt.optionId == option_id)
+                        .ok_or(AppError::Poll(PollsError::InvalidPollOption))?;
+                    if !seen_ids.insert(option_id.clone()) {
+                        return Err(AppError::Poll(PollsError::InvalidConfiguration(format!(
+                            "Option {} is listed more than once",
+                            option_id
+                        ))));
+                    }
+                    (option_id, current.votes)
+                }
+                None => (Uuid::new_v4().to_string(), 0),
+            };
+
+            new_options.push(PollOption {
+                optionName: option.optionName,
+                optionId: option_id,
+                votes,
+                displayOrder: index as i32,
+            });
+        }
+
+        let orphaned_votes: i32 = existing
+            .iter()
+            .filter(|opt| !seen_ids.contains(&opt.optionId))
+            .map(|opt| opt.votes)
+            .sum();
+
+        if orphaned_votes == 0 {
+            return Ok((new_options, None));
+        }
+
+        match &removed_votes {
+            Some(RemovedVotesStrategy::Migrate { .. }) if is_multi => {
+                return Err(AppError::Poll(PollsError::InvalidConfiguration(
+                    String::from(
+                        "Votes of multi-select polls can't be migrated, reset them instead",
+                    ),
+                )));
+            }
+            Some(RemovedVotesStrategy::Migrate { targetOptionId }) => {
+                let target = new_options
+                    .iter_mut()
+                    .find(|opt| &opt.optionId == targetOptionId && seen_ids.contains(&opt.optionId))
+                    .ok_or(AppError::Poll(PollsError::InvalidPollOption))?;
+                target.votes += orphaned_votes;
+            }
+            Some(RemovedVotesStrategy::Reset) => {
+                new_options.iter_mut().for_each(|opt| opt.votes = 0);
+            }
+            None => return Err(AppError::Poll(PollsError::OptionHasVotes)),
+        }
+        Ok((new_options, removed_votes))
+    }
+
     pub async fn get_polls_of_user(
         &self,
         user_id: String,
@@ -99,16 +269,16 @@ impl PollRepository {
         let poll_options = dto
             .options
             .iter()
-            .map(|poll| {
-                return PollOption {
-                    optionName: poll.clone(),
-                    optionId: Uuid::new_v4().to_string(),
-                    votes: 0,
-                };
+            .enumerate()
+            .map(|(index, poll)| PollOption {
+                optionName: poll.clone(),
+                optionId: Uuid::new_v4().to_string(),
+                votes: 0,
+                displayOrder: index as i32,
             })
             .collect::<Vec<PollOption>>();
 
-        let new_poll = Poll {
+        let mut new_poll = Poll {
             pollId: poll_id,
             createdBy: dto.createdBy,
             startDate: BsonDateTime::from_millis(dto.startDate.timestamp_millis()),
@@ -119,35 +289,426 @@ impl PollRepository {
             isClosed: false,
             options: poll_options,
             votedBy: [].to_vec(),
+            seriesId: dto.seriesId,
+            orgId: dto.orgId,
+            targetGroups: dto.targetGroups,
+            allowGuests: dto.allowGuests,
+            guestFingerprinting: dto.guestFingerprinting,
+            guestFingerprints: vec![],
+            resultsVisibility: dto.resultsVisibility,
+            shuffleOptions: dto.shuffleOptions,
+            passwordHash: None,
+            passwordChangedAt: None,
+            peakViewers: 0,
+            peakViewersAt: None,
+            isPrivate: dto.isPrivate,
+            inviteCode: Some(generate_invite_code()),
+            allowedUsers: vec![],
+            joinCode: None,
+            collaborators: vec![],
         };
 
         info!("Inserting new poll to db {:#?}", new_poll.pollId);
 
-        match self.polls.insert_one(&new_poll).await {
-            Ok(_) => Ok(new_poll.to_response_dto()),
-            Err(e) => Err(AppError::DatabaseError(e.to_string())),
+        // The unique index on join codes turns away a code another poll just took
+        for _ in 0..JOIN_CODE_ATTEMPTS {
+            let Some(join_code) = self.claimable_join_code().await? else {
+                continue;
+            };
+            new_poll.joinCode = Some(join_code);
+
+            match self.polls.insert_one(&new_poll).await {
+                Ok(_) => return Ok(new_poll.to_response_dto()),
+                Err(e) if is_duplicate_key(&e) => continue,
+                Err(e) => return Err(AppError::DatabaseError(e.to_string())),
+            }
         }
+
+        Err(Self::no_free_join_code())
     }
 
-    pub async fn get_all_polls(&self) -> Result<Vec<PollResponseDTO>, AppError> {
+    /// Creates a copy of a poll with the same options and settings but new dates,
+    /// zeroed counters and no voters
+    pub async fn clone_poll(
+        &self,
+        poll_id: String,
+        dto: ClonePollDTO,
+        created_by: String,
+    ) -> Result<PollResponseDTO, AppError> {
+        let mut source = self.find_poll(&poll_id).await?;
+
+        source.options.sort_by_key(|opt| opt.displayOrder);
+
+        self.create_poll(CreatePollDTO {
+            name: dto.name.unwrap_or(source.name),
+            isMulti: source.isMulti,
+            startDate: dto.startDate,
+            endDate: dto.endDate,
+            createdBy: created_by,
+            options: source
+                .options
+                .into_iter()
+                .map(|opt| opt.optionName)
+                .collect(),
+            isPrivate: source.isPrivate,
+            orgId: source.orgId,
+            targetGroups: source.targetGroups,
+            allowGuests: source.allowGuests,
+            guestFingerprinting: source.guestFingerprinting,
+            resultsVisibility: source.resultsVisibility,
+            shuffleOptions: source.shuffleOptions,
+            seriesId: None,
+        })
+        .await
+    }
+
+    pub async fn get_all_polls(&self, user_id: &str) -> Result<Vec<PollResponseDTO>, AppError> {
         let polls = self
             .polls
-            .find(mongodb::bson::doc! {})
+            .find(mongodb::bson::doc! { "isPrivate": { "$ne": true } })
             .sort(mongodb::bson::doc! { "startDate": -1 }) // -1 for descending order
             .await
             .map_err(|e| AppError::DatabaseError(e.to_string()))?;
 
-        let poll_list: Vec<PollResponseDTO> = polls
+        let org_roles = self.org_roles_of(user_id).await?;
+
+        let poll_list = polls
             .try_collect::<Vec<Poll>>()
             .await
             .map_err(|e| AppError::DatabaseError(e.to_string()))?
             .into_iter()
-            .map(|poll| poll.to_response_dto())
+            .filter(|poll| {
+                // Password protected polls are only listed to those who don't need the password
+                poll.passwordHash.is_none()
+                    || poll.permits(
+                        user_id,
+                        Self::role_in(&org_roles, poll),
+                        PollAction::ManageAccess,
+                    )
+            })
+            .map(|poll| {
+                let org_role = Self::role_in(&org_roles, &poll);
+                poll.into_response_dto_for(user_id, org_role)
+            })
             .collect();
 
         Ok(poll_list)
     }
 
+    /// Instances of a recurring series, oldest first
+    pub async fn get_polls_of_series(
+        &self,
+        series_id: &str,
+    ) -> Result<Vec<PollResponseDTO>, AppError> {
+        let polls = self
+            .polls
+            .find(mongodb::bson::doc! { "seriesId": series_id })
+            .sort(mongodb::bson::doc! { "startDate": 1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Poll>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|poll| poll.to_response_dto())
+            .collect();
+
+        Ok(polls)
+    }
+
+    /// Polls owned by an organisation, newest first. Members who manage them see all results,
+    /// join codes and voters.
+    pub async fn get_polls_of_org(
+        &self,
+        org_id: &str,
+        user_id: &str,
+        org_role: Option<OrgRole>,
+    ) -> Result<Vec<PollResponseDTO>, AppError> {
+        let polls = self
+            .polls
+            .find(mongodb::bson::doc! { "orgId": org_id })
+            .sort(mongodb::bson::doc! { "startDate": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Poll>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|poll| poll.into_response_dto_for(user_id, org_role))
+            .collect();
+
+        Ok(polls)
+    }
+
+    /// Hands polls of a deleted organisation back to their creators
+    pub async fn detach_org_polls(&self, org_id: &str) -> Result<(), AppError> {
+        self.polls
+            .update_many(
+                mongodb::bson::doc! { "orgId": org_id },
+                mongodb::bson::doc! { "$unset": { "orgId": "" } },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    // Role of the user in the organisation owning the poll, if any
+    async fn org_role(&self, poll: &Poll, user_id: &str) -> Result<Option<OrgRole>, AppError> {
+        let Some(org_id) = &poll.orgId else {
+            return Ok(None);
+        };
+
+        let organisation = self
+            .organisations
+            .find_one(mongodb::bson::doc! { "orgId": org_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(organisation.and_then(|org| org.role_of(user_id)))
+    }
+
+    /// Roles of the user in all organisations they belong to, by organisation id. Saves a
+    /// lookup per poll when going through many of them.
+    async fn org_roles_of(&self, user_id: &str) -> Result<HashMap<String, OrgRole>, AppError> {
+        let organisations = self
+            .organisations
+            .find(mongodb::bson::doc! { "members.userId": user_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Organisation>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(organisations
+            .into_iter()
+            .filter_map(|org| org.role_of(user_id).map(|role| (org.orgId, role)))
+            .collect())
+    }
+
+    fn role_in(org_roles: &HashMap<String, OrgRole>, poll: &Poll) -> Option<OrgRole> {
+        poll.orgId
+            .as_ref()
+            .and_then(|org_id| org_roles.get(org_id).copied())
+    }
+
+    async fn find_poll(&self, poll_id: &str) -> Result<Poll, AppError> {
+        self.polls
+            .find_one(mongodb::bson::doc! { "pollId": poll_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::PollNotFound))
+    }
+
+    /// Fails unless the poll is public, or the user owns it, is on its allow-list
+    /// or belongs to the organisation owning it
+    pub async fn verify_poll_access(&self, poll_id: &str, user_id: &str) -> Result<(), AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        if !poll.is_accessible_by(user_id) && self.org_role(&poll, user_id).await?.is_none() {
+            return Err(AppError::Poll(PollsError::PrivatePoll));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_poll_access(&self, poll_id: &str) -> Result<PollAccessResponseDTO, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        Ok(PollAccessResponseDTO {
+            poll_id: poll.pollId,
+            is_private: poll.isPrivate,
+            invite_code: poll.inviteCode,
+            allowed_users: poll.allowedUsers,
+        })
+    }
+
+    /// Adds the user to the allow-list if the code is the poll's current invite code
+    pub async fn redeem_invite_code(
+        &self,
+        poll_id: &str,
+        invite_code: &str,
+        user_id: &str,
+    ) -> Result<(), AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        if poll.inviteCode.as_deref() != Some(invite_code) {
+            return Err(AppError::Poll(PollsError::InvalidInviteCode));
+        }
+
+        self.allow_user(poll_id, user_id).await
+    }
+
+    pub async fn allow_user(&self, poll_id: &str, user_id: &str) -> Result<(), AppError> {
+        self.update_access(
+            poll_id,
+            mongodb::bson::doc! { "$addToSet": { "allowedUsers": user_id } },
+        )
+        .await
+    }
+
+    /// Removes the user from the allow-list, they keep any vote already cast
+    pub async fn revoke_user(&self, poll_id: &str, user_id: &str) -> Result<(), AppError> {
+        self.update_access(
+            poll_id,
+            mongodb::bson::doc! { "$pull": { "allowedUsers": user_id } },
+        )
+        .await
+    }
+
+    /// Replaces the invite code, old links stop working but already admitted users stay
+    pub async fn rotate_invite_code(&self, poll_id: &str) -> Result<(), AppError> {
+        self.update_access(
+            poll_id,
+            mongodb::bson::doc! { "$set": { "inviteCode": generate_invite_code() } },
+        )
+        .await
+    }
+
+    /// Filter matching the poll a join code currently points to.
+    /// Codes of closed or ended polls are expired and may be handed out again.
+    fn active_join_code_filter(join_code: &str) -> mongodb::bson::Document {
+        mongodb::bson::doc! {
+            "joinCode": join_code,
+            "isClosed": false,
+            "endDate": { "$gt": BsonDateTime::now() }
+        }
+    }
+
+    /// Join codes point to a single poll. Expired codes are released before being handed
+    /// out again, so the index only covers codes that are set.
+    pub async fn create_indexes(&self) -> Result<(), AppError> {
+        let index = IndexModel::builder()
+            .keys(mongodb::bson::doc! { "joinCode": 1 })
+            .options(
+                IndexOptions::builder()
+                    .unique(true)
+                    .partial_filter_expression(
+                        mongodb::bson::doc! { "joinCode": { "$type": "string" } },
+                    )
+                    .build(),
+            )
+            .build();
+
+        self.polls
+            .create_index(index)
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// A random join code no open poll uses, taken away from the closed or ended polls that
+    /// still hold it. `None` when an open poll has it, writing the code can still fail when
+    /// another poll claims it first.
+    async fn claimable_join_code(&self) -> Result<Option<String>, AppError> {
+        let join_code = generate_join_code();
+        let taken = self
+            .polls
+            .count_documents(Self::active_join_code_filter(&join_code))
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            > 0;
+        if taken {
+            return Ok(None);
+        }
+
+        self.polls
+            .update_many(
+                mongodb::bson::doc! {
+                    "joinCode": &join_code,
+                    "$or": [
+                        { "isClosed": true },
+                        { "endDate": { "$lte": BsonDateTime::now() } }
+                    ]
+                },
+                mongodb::bson::doc! { "$set": { "joinCode": null } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(Some(join_code))
+    }
+
+    fn no_free_join_code() -> AppError {
+        AppError::Poll(PollsError::CreationFailed(
+            "Could not find a free join code".to_string(),
+        ))
+    }
+
+    pub async fn find_by_join_code(
+        &self,
+        join_code: &str,
+    ) -> Result<JoinCodeResponseDTO, AppError> {
+        let join_code = normalize_join_code(join_code);
+        let poll = self
+            .polls
+            .find_one(Self::active_join_code_filter(&join_code))
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::JoinCodeNotFound))?;
+
+        Ok(JoinCodeResponseDTO {
+            poll_id: poll.pollId,
+            name: poll.name,
+            join_code,
+        })
+    }
+
+    /// Gives an open poll a fresh join code, the old one stops working
+    pub async fn regenerate_join_code(
+        &self,
+        poll_id: &str,
+    ) -> Result<JoinCodeResponseDTO, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+        if poll.isClosed {
+            return Err(AppError::Poll(PollsError::CannotModifyClosed));
+        }
+
+        for _ in 0..JOIN_CODE_ATTEMPTS {
+            let Some(join_code) = self.claimable_join_code().await? else {
+                continue;
+            };
+
+            match self
+                .polls
+                .update_one(
+                    mongodb::bson::doc! { "pollId": poll_id },
+                    mongodb::bson::doc! { "$set": { "joinCode": &join_code } },
+                )
+                .await
+            {
+                Ok(_) => {
+                    return Ok(JoinCodeResponseDTO {
+                        poll_id: poll.pollId,
+                        name: poll.name,
+                        join_code,
+                    })
+                }
+                Err(e) if is_duplicate_key(&e) => continue,
+                Err(e) => return Err(AppError::DatabaseError(e.to_string())),
+            }
+        }
+
+        Err(Self::no_free_join_code())
+    }
+
+    async fn update_access(
+        &self,
+        poll_id: &str,
+        update: mongodb::bson::Document,
+    ) -> Result<(), AppError> {
+        let update_result = self
+            .polls
+            .update_one(mongodb::bson::doc! { "pollId": poll_id }, update)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if update_result.matched_count == 0 {
+            return Err(AppError::Poll(PollsError::PollNotFound));
+        }
+
+        Ok(())
+    }
+
     pub async fn get_poll_by_id(
         &self,
         poll_id: String,
@@ -161,16 +722,53 @@ impl PollRepository {
         Ok(poll.map(|p| p.to_response_dto()))
     }
 
+    /// The poll as anonymous audiences may see it. `None` when it's missing or restricted to
+    /// some users, vote counts are left out unless its results visibility shows them to all
+    /// and voters always.
+    pub async fn get_poll_for_audience(
+        &self,
+        poll_id: &str,
+    ) -> Result<Option<PollResponseDTO>, AppError> {
+        let poll = self
+            .polls
+            .find_one(mongodb::bson::doc! { "pollId": poll_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(poll
+            .filter(|poll| {
+                !poll.isPrivate && poll.passwordHash.is_none() && poll.targetGroups.is_empty()
+            })
+            .map(|poll| poll.into_response_dto_for("", None)))
+    }
+
     pub async fn can_vote(&self, user_id: String, poll_id: String) -> Result<bool, AppError> {
-        match self.get_poll_by_id(poll_id).await {
-            Ok(Some(poll)) => {
-                // Check if user has already voted
-                let has_voted = poll.voted_by.contains(&user_id);
-                Ok(!has_voted)
-            }
-            Ok(None) => Err(AppError::Poll(PollsError::PollNotFound)),
-            Err(e) => Err(AppError::DatabaseError(e.to_string())),
+        let poll = self.find_poll(&poll_id).await?;
+        self.verify_target_groups(&poll, &user_id).await?;
+
+        Ok(!has_voted(&poll.votedBy, &user_id))
+    }
+
+    /// Fails unless the poll targets no groups or the user is in one of them
+    async fn verify_target_groups(&self, poll: &Poll, user_id: &str) -> Result<(), AppError> {
+        if poll.targetGroups.is_empty() {
+            return Ok(());
         }
+
+        let memberships = self
+            .groups
+            .count_documents(mongodb::bson::doc! {
+                "groupId": { "$in": &poll.targetGroups },
+                "members": user_id
+            })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if memberships == 0 {
+            return Err(AppError::Poll(PollsError::NotInTargetGroup));
+        }
+
+        Ok(())
     }
 
     pub async fn cast_vote(
@@ -192,7 +790,7 @@ impl PollRepository {
             return Err(AppError::Poll(PollsError::PollPaused));
         }
 
-        if let Ok(false) = self.can_vote(user_id.clone(), poll_id.clone()).await {
+        if !self.can_vote(user_id.clone(), poll_id.clone()).await? {
             return Err(AppError::Poll(PollsError::AlreadyVoted));
         }
 
@@ -224,14 +822,390 @@ impl PollRepository {
             .ok_or(AppError::Poll(PollsError::PollNotFound))
     }
 
-    // Add new method to verify poll ownership
-    pub async fn verify_poll_owner(&self, poll_id: &str, user_id: &str) -> Result<bool, AppError> {
-        let poll = self
-            .get_poll_by_id(poll_id.to_string())
+    /// Fetches a poll that accepts guest votes
+    pub async fn get_guest_poll(&self, poll_id: &str) -> Result<Poll, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        if poll.isPrivate {
+            return Err(AppError::Poll(PollsError::PrivatePoll));
+        }
+        if !poll.allowGuests {
+            return Err(AppError::Poll(PollsError::GuestVotingDisabled));
+        }
+
+        Ok(poll)
+    }
+
+    pub async fn can_guest_vote(
+        &self,
+        poll_id: &str,
+        guest_id: &str,
+        fingerprint: &str,
+    ) -> Result<bool, AppError> {
+        let poll = self.get_guest_poll(poll_id).await?;
+
+        if poll.guestFingerprinting && poll.guestFingerprints.iter().any(|f| f == fingerprint) {
+            return Ok(false);
+        }
+
+        self.can_vote(guest_id.to_string(), poll_id.to_string())
+            .await
+    }
+
+    /// Casts a guest vote, remembering the device fingerprint when the poll checks them
+    pub async fn cast_guest_vote(
+        &self,
+        poll_id: String,
+        option_id: String,
+        guest_id: String,
+        fingerprint: String,
+    ) -> Result<PollResponseDTO, AppError> {
+        if !self
+            .can_guest_vote(&poll_id, &guest_id, &fingerprint)
             .await?
-            .ok_or(AppError::Poll(PollsError::PollNotFound))?;
+        {
+            return Err(AppError::Poll(PollsError::AlreadyVoted));
+        }
+
+        let poll = self.cast_vote(poll_id.clone(), option_id, guest_id).await?;
+
+        if poll.guest_fingerprinting {
+            self.polls
+                .update_one(
+                    mongodb::bson::doc! { "pollId": &poll_id },
+                    mongodb::bson::doc! { "$addToSet": { "guestFingerprints": fingerprint } },
+                )
+                .await
+                .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        }
+
+        Ok(poll)
+    }
+
+    /// Fails unless the user may perform `action` on the poll. Its creator and editors of the
+    /// owning organisation may do anything, collaborators what their role allows.
+    pub async fn verify_poll_permission(
+        &self,
+        poll_id: &str,
+        user_id: &str,
+        action: PollAction,
+    ) -> Result<(), AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        if !self.has_permission(&poll, user_id, action).await? {
+            return Err(AppError::Poll(PollsError::Unauthorized));
+        }
+
+        Ok(())
+    }
+
+    /// Same as `verify_poll_permission` for several polls at once, skipping those that were
+    /// deleted
+    pub async fn verify_polls_permission(
+        &self,
+        poll_ids: &[String],
+        user_id: &str,
+        action: PollAction,
+    ) -> Result<(), AppError> {
+        let polls = self
+            .polls
+            .find(mongodb::bson::doc! { "pollId": { "$in": poll_ids } })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Poll>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let org_roles = self.org_roles_of(user_id).await?;
+
+        if !polls
+            .iter()
+            .all(|poll| poll.permits(user_id, Self::role_in(&org_roles, poll), action))
+        {
+            return Err(AppError::Poll(PollsError::Unauthorized));
+        }
+
+        Ok(())
+    }
+
+    async fn has_permission(
+        &self,
+        poll: &Poll,
+        user_id: &str,
+        action: PollAction,
+    ) -> Result<bool, AppError> {
+        // Only look up the organisation when the poll alone doesn't allow it
+        Ok(poll.permits(user_id, None, action)
+            || poll.permits(user_id, self.org_role(poll, user_id).await?, action))
+    }
+
+    /// Whether the results visibility of the poll lets the user see vote counts
+    pub async fn can_view_results(&self, poll_id: &str, user_id: &str) -> Result<bool, AppError> {
+        let poll = self.find_poll(poll_id).await?;
 
-        Ok(poll.created_by == user_id)
+        Ok(poll.results_visible_to(user_id)
+            || self
+                .has_permission(&poll, user_id, PollAction::ViewResults)
+                .await?)
+    }
+
+    /// The poll as the user may see it, without vote counts if results are hidden from them.
+    /// Voters of polls with shuffled options get them in their own order, editors the
+    /// canonical one.
+    pub async fn get_poll_for_user(
+        &self,
+        poll_id: &str,
+        user_id: &str,
+    ) -> Result<PollResponseDTO, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+        let org_role = self.org_role(&poll, user_id).await?;
+        let shuffle = poll.shuffleOptions && !poll.permits(user_id, org_role, PollAction::Edit);
+
+        let dto = poll.into_response_dto_for(user_id, org_role);
+        if shuffle {
+            Ok(dto.with_options_shuffled_for(user_id))
+        } else {
+            Ok(dto)
+        }
+    }
+
+    /// Sets or, given `None`, removes the poll password. Access tokens issued before
+    /// the change stop working.
+    pub async fn set_password(
+        &self,
+        poll_id: &str,
+        password_hash: Option<String>,
+    ) -> Result<(), AppError> {
+        let update_result = self
+            .polls
+            .update_one(
+                mongodb::bson::doc! { "pollId": poll_id },
+                mongodb::bson::doc! { "$set": {
+                    "passwordHash": password_hash,
+                    "passwordChangedAt": BsonDateTime::now(),
+                } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if update_result.matched_count == 0 {
+            return Err(AppError::Poll(PollsError::PollNotFound));
+        }
+
+        Ok(())
+    }
+
+    pub async fn get_password_hash(&self, poll_id: &str) -> Result<Option<String>, AppError> {
+        Ok(self.find_poll(poll_id).await?.passwordHash)
+    }
+
+    /// When the user needs an access token for the poll, the time in seconds its password
+    /// was last changed. Whoever manages access to the poll never needs one.
+    pub async fn get_password_lock(
+        &self,
+        poll_id: &str,
+        user_id: &str,
+    ) -> Result<Option<i64>, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        if poll.passwordHash.is_none()
+            || self
+                .has_permission(&poll, user_id, PollAction::ManageAccess)
+                .await?
+        {
+            return Ok(None);
+        }
+
+        Ok(Some(poll.passwordChangedAt.map_or(0, |changed_at| {
+            changed_at.timestamp_millis() / 1000
+        })))
+    }
+
+    pub async fn get_voters(&self, poll_id: &str) -> Result<Vec<String>, AppError> {
+        Ok(self.find_poll(poll_id).await?.votedBy)
+    }
+
+    /// Raises the peak viewer count of the poll if `viewers` beats it
+    pub async fn record_viewers(&self, poll_id: &str, viewers: usize) -> Result<(), AppError> {
+        let viewers = viewers as i64;
+
+        self.polls
+            .update_one(
+                mongodb::bson::doc! { "pollId": poll_id, "peakViewers": { "$not": { "$gte": viewers } } },
+                mongodb::bson::doc! {
+                    "$set": { "peakViewers": viewers, "peakViewersAt": BsonDateTime::now() }
+                },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn get_analytics(
+        &self,
+        poll_id: &str,
+        current_viewers: usize,
+    ) -> Result<PollAnalyticsDTO, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        Ok(PollAnalyticsDTO {
+            poll_id: poll.pollId,
+            total_votes: poll.options.iter().map(|option| option.votes).sum(),
+            voters: poll.votedBy.len(),
+            current_viewers,
+            peak_viewers: poll.peakViewers as usize,
+            peak_viewers_at: poll.peakViewersAt.map(|at| at.to_string()),
+        })
+    }
+
+    pub async fn get_collaborators(
+        &self,
+        poll_id: &str,
+    ) -> Result<Vec<PollCollaboratorDTO>, AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        Ok(poll
+            .collaborators
+            .iter()
+            .map(|c| PollCollaboratorDTO {
+                user_id: c.userId.clone(),
+                role: c.role,
+                accepted: c.accepted,
+                invited_by: c.invitedBy.clone(),
+                invited_at: c.invitedAt.to_string(),
+            })
+            .collect())
+    }
+
+    /// Invites a user as a collaborator. Inviting an existing collaborator changes their role
+    /// without asking them to accept again.
+    pub async fn invite_collaborator(
+        &self,
+        poll_id: &str,
+        user_id: &str,
+        role: CollaboratorRole,
+        invited_by: &str,
+    ) -> Result<(), AppError> {
+        let poll = self.find_poll(poll_id).await?;
+
+        if poll.createdBy == user_id {
+            return Err(AppError::Poll(PollsError::InvalidConfiguration(
+                "The poll owner can't be a collaborator".to_string(),
+            )));
+        }
+
+        let role_bson =
+            mongodb::bson::to_bson(&role).map_err(|e| AppError::DatabaseError(e.to_string()))?;
+        let update = if poll.collaborators.iter().any(|c| c.userId == user_id) {
+            self.polls
+                .update_one(
+                    mongodb::bson::doc! { "pollId": poll_id, "collaborators.userId": user_id },
+                    mongodb::bson::doc! { "$set": { "collaborators.$.role": role_bson } },
+                )
+                .await
+        } else {
+            let collaborator = mongodb::bson::to_bson(&PollCollaborator {
+                userId: user_id.to_string(),
+                role,
+                accepted: false,
+                invitedBy: invited_by.to_string(),
+                invitedAt: BsonDateTime::now(),
+            })
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+            self.polls
+                .update_one(
+                    mongodb::bson::doc! { "pollId": poll_id, "collaborators.userId": { "$ne": user_id } },
+                    mongodb::bson::doc! { "$push": { "collaborators": collaborator } },
+                )
+                .await
+        };
+        update.map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        info!("Invited {} to collaborate on poll {}", user_id, poll_id);
+
+        Ok(())
+    }
+
+    /// Accepting activates the invitation, declining removes it
+    pub async fn respond_to_invitation(
+        &self,
+        poll_id: &str,
+        user_id: &str,
+        accept: bool,
+    ) -> Result<(), AppError> {
+        let filter = mongodb::bson::doc! {
+            "pollId": poll_id,
+            "collaborators": { "$elemMatch": { "userId": user_id, "accepted": false } }
+        };
+        let update = if accept {
+            mongodb::bson::doc! { "$set": { "collaborators.$.accepted": true } }
+        } else {
+            mongodb::bson::doc! { "$pull": { "collaborators": { "userId": user_id } } }
+        };
+
+        let update_result = self
+            .polls
+            .update_one(filter, update)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if update_result.matched_count == 0 {
+            return Err(AppError::Poll(PollsError::CollaboratorNotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Removes a collaborator or withdraws a pending invitation
+    pub async fn remove_collaborator(&self, poll_id: &str, user_id: &str) -> Result<(), AppError> {
+        let update_result = self
+            .polls
+            .update_one(
+                mongodb::bson::doc! { "pollId": poll_id, "collaborators.userId": user_id },
+                mongodb::bson::doc! { "$pull": { "collaborators": { "userId": user_id } } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if update_result.matched_count == 0 {
+            return Err(AppError::Poll(PollsError::CollaboratorNotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Invitations the user hasn't answered yet
+    pub async fn get_pending_invitations(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<CollaboratorInviteDTO>, AppError> {
+        let polls = self
+            .polls
+            .find(mongodb::bson::doc! {
+                "collaborators": { "$elemMatch": { "userId": user_id, "accepted": false } }
+            })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Poll>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(polls
+            .iter()
+            .flat_map(|poll| {
+                poll.collaborators
+                    .iter()
+                    .filter(|c| !c.accepted && c.userId == user_id)
+                    .map(|c| CollaboratorInviteDTO {
+                        poll_id: poll.pollId.clone(),
+                        poll_name: poll.name.clone(),
+                        role: c.role,
+                        invited_by: c.invitedBy.clone(),
+                        invited_at: c.invitedAt.to_string(),
+                    })
+            })
+            .collect())
     }
 
     pub async fn close_poll(&self, poll_id: String) -> Result<PollResponseDTO, AppError> {
@@ -239,7 +1213,10 @@ impl PollRepository {
             .polls
             .update_one(
                 mongodb::bson::doc! { "pollId": &poll_id },
-                mongodb::bson::doc! { "$set": { "isClosed": true } },
+                mongodb::bson::doc! {
+                    "$set": { "isClosed": true },
+                    "$unset": { "joinCode": "" }
+                },
             )
             .await
             .map_err(|e| AppError::DatabaseError(e.to_string()))?;
@@ -253,6 +1230,55 @@ impl PollRepository {
             .ok_or(AppError::Poll(PollsError::PollNotFound))
     }
 
+    /// Paused polls stay visible but don't accept votes
+    pub async fn set_paused(
+        &self,
+        poll_id: String,
+        paused: bool,
+    ) -> Result<PollResponseDTO, AppError> {
+        let update_result = self
+            .polls
+            .update_one(
+                mongodb::bson::doc! { "pollId": &poll_id },
+                mongodb::bson::doc! { "$set": { "isPaused": paused } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if update_result.matched_count == 0 {
+            return Err(AppError::Poll(PollsError::PollNotFound));
+        }
+
+        self.get_poll_by_id(poll_id)
+            .await?
+            .ok_or(AppError::Poll(PollsError::PollNotFound))
+    }
+
+    /// Pauses those of the polls that aren't yet, returning all of them as they are now
+    pub async fn pause_polls(&self, poll_ids: &[String]) -> Result<Vec<PollResponseDTO>, AppError> {
+        self.polls
+            .update_many(
+                mongodb::bson::doc! { "pollId": { "$in": poll_ids }, "isPaused": false },
+                mongodb::bson::doc! { "$set": { "isPaused": true } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        let polls = self
+            .polls
+            .find(mongodb::bson::doc! { "pollId": { "$in": poll_ids } })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Poll>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|poll| poll.to_response_dto())
+            .collect();
+
+        Ok(polls)
+    }
+
     pub async fn reset_poll(&self, poll_id: String) -> Result<PollResponseDTO, AppError> {
         let poll = self
             .get_poll_by_id(poll_id.clone())
@@ -286,9 +1312,12 @@ impl PollRepository {
             .ok_or(AppError::Poll(PollsError::PollNotFound))
     }
 
+    /// The poll with all vote counts, for results and live views. Leaves out the join code and
+    /// voters, which these views show to people who don't manage the poll.
     pub async fn get_poll_results(&self, poll_id: String) -> Result<PollResponseDTO, AppError> {
         self.get_poll_by_id(poll_id)
             .await?
+            .map(|poll| poll.without_join_code().without_voters())
             .ok_or(AppError::Poll(PollsError::PollNotFound))
     }
 }
@@ -0,0 +1,209 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, DateTime as BsonDateTime},
+    options::IndexOptions,
+    Collection, IndexModel,
+};
+use serde_json::{json, Value};
+use tracing::info;
+
+use crate::{
+    config::db::is_duplicate_key,
+    dtos::{
+        requests::RemovedVotesStrategy,
+        responses::{PollResponseDTO, PollRevisionDiffDTO, PollRevisionResponseDTO},
+    },
+    error::{AppError, PollsError},
+    models::poll_revision::{FieldChange, PollRevision, PollSnapshot},
+};
+
+// Tries at recording an edit while others take the revision number
+const REVISION_ATTEMPTS: usize = 5;
+
+#[derive(Clone)]
+pub struct PollRevisionRepository {
+    revisions: Collection<PollRevision>,
+}
+
+impl PollRevisionRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let revisions = db.collection::<PollRevision>("poll_revisions");
+        Self { revisions }
+    }
+
+    /// Revision numbers are unique per poll, concurrent edits rely on it
+    pub async fn create_indexes(&self) -> Result<(), AppError> {
+        let index = IndexModel::builder()
+            .keys(doc! { "pollId": 1, "revision": 1 })
+            .options(IndexOptions::builder().unique(true).build())
+            .build();
+
+        self.revisions
+            .create_index(index)
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Stores revision 1 for a freshly created poll
+    pub async fn record_creation(&self, poll: &PollResponseDTO) -> Result<(), AppError> {
+        self.insert(Self::first_revision(poll))
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Appends a revision describing the edit from `before` to `after`, along with what was
+    /// done to the votes of removed options. Edits that didn't change anything are not recorded.
+    pub async fn record_edit(
+        &self,
+        before: &PollResponseDTO,
+        after: &PollResponseDTO,
+        removed_votes: Option<&RemovedVotesStrategy>,
+        edited_by: &str,
+    ) -> Result<(), AppError> {
+        // Concurrent edits race for the next revision number. The unique index gives it to
+        // one of them, the others diff against the winner and try the number after.
+        for _ in 0..REVISION_ATTEMPTS {
+            let latest = self.get_or_record_baseline(before).await?;
+            let snapshot = PollSnapshot::from_response(after);
+            let mut changes = latest.snapshot.diff(&snapshot);
+            // Votes aren't part of snapshots, only the edit tells where they went
+            if let Some(strategy) = removed_votes {
+                changes.push(FieldChange {
+                    field: "removedVotes".to_string(),
+                    before: Value::Null,
+                    after: json!(strategy),
+                });
+            }
+            if changes.is_empty() {
+                return Ok(());
+            }
+
+            let revision = PollRevision {
+                pollId: after.poll_id.clone(),
+                revision: latest.revision + 1,
+                editedBy: edited_by.to_string(),
+                editedAt: BsonDateTime::now(),
+                changes,
+                snapshot,
+            };
+            match self.insert(revision).await {
+                Ok(()) => return Ok(()),
+                Err(e) if is_duplicate_key(&e) => continue,
+                Err(e) => return Err(AppError::DatabaseError(e.to_string())),
+            }
+        }
+
+        Err(AppError::DatabaseError(format!(
+            "Could not record a revision of poll {}",
+            after.poll_id
+        )))
+    }
+
+    // Polls created before revisions existed get their pre-edit state as a baseline
+    async fn get_or_record_baseline(
+        &self,
+        before: &PollResponseDTO,
+    ) -> Result<PollRevision, AppError> {
+        if let Some(revision) = self.get_latest_revision(&before.poll_id).await? {
+            return Ok(revision);
+        }
+
+        info!("Recording baseline revision for poll {}", before.poll_id);
+        match self.insert(Self::first_revision(before)).await {
+            // Another edit may have recorded it meanwhile
+            Err(e) if !is_duplicate_key(&e) => return Err(AppError::DatabaseError(e.to_string())),
+            _ => {}
+        }
+        self.get_latest_revision(&before.poll_id)
+            .await?
+            .ok_or(AppError::Poll(PollsError::RevisionNotFound))
+    }
+
+    pub async fn get_revisions(
+        &self,
+        poll_id: String,
+    ) -> Result<Vec<PollRevisionResponseDTO>, AppError> {
+        let revisions = self
+            .revisions
+            .find(doc! { "pollId": poll_id })
+            .sort(doc! { "revision": 1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<PollRevision>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .into_iter()
+            .map(|revision| revision.to_response_dto())
+            .collect();
+
+        Ok(revisions)
+    }
+
+    /// Compares the snapshots of two revisions, `to` defaults to the latest one
+    pub async fn diff_revisions(
+        &self,
+        poll_id: String,
+        from: i32,
+        to: Option<i32>,
+    ) -> Result<PollRevisionDiffDTO, AppError> {
+        let from_revision = self.get_revision(&poll_id, from).await?;
+        let to_revision = match to {
+            Some(to) => self.get_revision(&poll_id, to).await?,
+            None => self
+                .get_latest_revision(&poll_id)
+                .await?
+                .ok_or(AppError::Poll(PollsError::RevisionNotFound))?,
+        };
+
+        Ok(PollRevisionDiffDTO {
+            poll_id,
+            from_revision: from_revision.revision,
+            to_revision: to_revision.revision,
+            changes: from_revision
+                .snapshot
+                .diff(&to_revision.snapshot)
+                .iter()
+                .map(|change| change.to_response_dto())
+                .collect(),
+        })
+    }
+
+    async fn get_revision(&self, poll_id: &str, revision: i32) -> Result<PollRevision, AppError> {
+        self.revisions
+            .find_one(doc! { "pollId": poll_id, "revision": revision })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::RevisionNotFound))
+    }
+
+    async fn get_latest_revision(&self, poll_id: &str) -> Result<Option<PollRevision>, AppError> {
+        self.revisions
+            .find_one(doc! { "pollId": poll_id })
+            .sort(doc! { "revision": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    fn first_revision(poll: &PollResponseDTO) -> PollRevision {
+        PollRevision {
+            pollId: poll.poll_id.clone(),
+            revision: 1,
+            editedBy: poll.created_by.clone(),
+            editedAt: BsonDateTime::now(),
+            changes: vec![],
+            snapshot: PollSnapshot::from_response(poll),
+        }
+    }
+
+    async fn insert(&self, revision: PollRevision) -> mongodb::error::Result<()> {
+        info!(
+            "Recording revision {} of poll {}",
+            revision.revision, revision.pollId
+        );
+
+        self.revisions.insert_one(&revision).await.map(|_| ())
+    }
+}
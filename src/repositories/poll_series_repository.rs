@@ -0,0 +1,184 @@
+use std::{collections::HashMap, sync::Arc};
+
+use chrono::{DateTime, Utc};
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    dtos::{requests::CreatePollSeriesDTO, responses::PollSeriesResponseDTO},
+    error::{AppError, PollsError},
+    models::poll_series::PollSeries,
+    utils::{recurrence, template},
+};
+
+#[derive(Clone)]
+pub struct PollSeriesRepository {
+    series: Collection<PollSeries>,
+}
+
+impl PollSeriesRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let series = db.collection::<PollSeries>("poll_series");
+        Self { series }
+    }
+
+    pub async fn create_series(
+        &self,
+        dto: CreatePollSeriesDTO,
+        created_by: String,
+    ) -> Result<PollSeriesResponseDTO, AppError> {
+        recurrence::validate(&dto.recurrence)
+            .map_err(|e| AppError::Poll(PollsError::InvalidConfiguration(e)))?;
+
+        // `{{date}}` is the only value known when an instance is generated
+        let params = HashMap::from([("date".to_string(), String::new())]);
+        template::render(&dto.name, &params).map_err(|param| {
+            AppError::Poll(PollsError::InvalidConfiguration(format!(
+                "Unsupported placeholder '{}' in series name",
+                param
+            )))
+        })?;
+
+        let next_run_at = recurrence::next_occurrence(&dto.recurrence, Utc::now())
+            .map_err(|e| AppError::Poll(PollsError::InvalidConfiguration(e)))?;
+
+        let series = PollSeries {
+            seriesId: Uuid::new_v4().to_string(),
+            createdBy: created_by,
+            name: dto.name,
+            isMulti: dto.isMulti,
+            options: dto.options,
+            recurrence: dto.recurrence,
+            nextRunAt: BsonDateTime::from_millis(next_run_at.timestamp_millis()),
+            isActive: true,
+            createdAt: BsonDateTime::now(),
+        };
+
+        info!("Inserting new poll series to db {:#?}", series.seriesId);
+
+        self.series
+            .insert_one(&series)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(series.to_response_dto())
+    }
+
+    pub async fn get_series_of_user(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<PollSeriesResponseDTO>, AppError> {
+        let series = self
+            .series
+            .find(doc! { "createdBy": user_id })
+            .sort(doc! { "createdAt": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<PollSeries>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .iter()
+            .map(|series| series.to_response_dto())
+            .collect();
+
+        Ok(series)
+    }
+
+    pub async fn get_series_by_id(&self, series_id: String) -> Result<PollSeries, AppError> {
+        self.series
+            .find_one(doc! { "seriesId": series_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::SeriesNotFound))
+    }
+
+    /// Pauses or resumes generation. Resuming skips the occurrences missed while paused.
+    pub async fn set_active(
+        &self,
+        series: &PollSeries,
+        is_active: bool,
+    ) -> Result<PollSeriesResponseDTO, AppError> {
+        let mut set_doc = doc! { "isActive": is_active };
+        if is_active {
+            let next_run_at = recurrence::next_occurrence(&series.recurrence, Utc::now())
+                .map_err(|e| AppError::Poll(PollsError::InvalidConfiguration(e)))?;
+            set_doc.insert(
+                "nextRunAt",
+                BsonDateTime::from_millis(next_run_at.timestamp_millis()),
+            );
+        }
+
+        self.series
+            .update_one(
+                doc! { "seriesId": &series.seriesId },
+                doc! { "$set": set_doc },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(self
+            .get_series_by_id(series.seriesId.clone())
+            .await?
+            .to_response_dto())
+    }
+
+    /// Removes the series, polls it already generated are kept
+    pub async fn delete_series(&self, series_id: String) -> Result<(), AppError> {
+        let delete_result = self
+            .series
+            .delete_one(doc! { "seriesId": series_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if delete_result.deleted_count == 0 {
+            return Err(AppError::Poll(PollsError::SeriesNotFound));
+        }
+
+        Ok(())
+    }
+
+    /// Active series whose next instance should have started by `now`
+    pub async fn get_due_series(&self, now: DateTime<Utc>) -> Result<Vec<PollSeries>, AppError> {
+        self.series
+            .find(doc! {
+                "isActive": true,
+                "nextRunAt": { "$lte": BsonDateTime::from_millis(now.timestamp_millis()) }
+            })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<PollSeries>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    /// Moves the series on to its following occurrence.
+    /// Returns false if another server instance already did, in which case it also generated
+    /// the poll for the current occurrence.
+    pub async fn claim_run(
+        &self,
+        series: &PollSeries,
+        next_run_at: DateTime<Utc>,
+    ) -> Result<bool, AppError> {
+        let claimed = self
+            .series
+            .find_one_and_update(
+                doc! {
+                    "seriesId": &series.seriesId,
+                    "isActive": true,
+                    "nextRunAt": series.nextRunAt
+                },
+                doc! {
+                    "$set": { "nextRunAt": BsonDateTime::from_millis(next_run_at.timestamp_millis()) }
+                },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(claimed.is_some())
+    }
+}
@@ -0,0 +1,109 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    dtos::{requests::CreateTemplateDTO, responses::PollTemplateResponseDTO},
+    error::{AppError, PollsError},
+    models::poll_template::PollTemplate,
+};
+
+#[derive(Clone)]
+pub struct PollTemplateRepository {
+    templates: Collection<PollTemplate>,
+}
+
+impl PollTemplateRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let templates = db.collection::<PollTemplate>("poll_templates");
+        Self { templates }
+    }
+
+    pub async fn create_template(
+        &self,
+        dto: CreateTemplateDTO,
+        created_by: String,
+    ) -> Result<PollTemplateResponseDTO, AppError> {
+        let template = PollTemplate {
+            templateId: Uuid::new_v4().to_string(),
+            createdBy: created_by,
+            title: dto.title,
+            name: dto.name,
+            isMulti: dto.isMulti,
+            options: dto.options,
+            createdAt: BsonDateTime::now(),
+        };
+
+        info!(
+            "Inserting new poll template to db {:#?}",
+            template.templateId
+        );
+
+        self.templates
+            .insert_one(&template)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(template.to_response_dto())
+    }
+
+    pub async fn get_templates_of_user(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<PollTemplateResponseDTO>, AppError> {
+        let templates = self
+            .templates
+            .find(doc! { "createdBy": user_id })
+            .sort(doc! { "createdAt": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<PollTemplate>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .iter()
+            .map(|template| template.to_response_dto())
+            .collect();
+
+        Ok(templates)
+    }
+
+    /// Fetches a template, only its creator is allowed to see or use it
+    pub async fn get_template_of_user(
+        &self,
+        template_id: String,
+        user_id: &str,
+    ) -> Result<PollTemplate, AppError> {
+        let template = self
+            .templates
+            .find_one(doc! { "templateId": template_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::TemplateNotFound))?;
+
+        if template.createdBy != user_id {
+            return Err(AppError::Poll(PollsError::Unauthorized));
+        }
+
+        Ok(template)
+    }
+
+    pub async fn delete_template(&self, template_id: String) -> Result<(), AppError> {
+        let delete_result = self
+            .templates
+            .delete_one(doc! { "templateId": template_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if delete_result.deleted_count == 0 {
+            return Err(AppError::Poll(PollsError::TemplateNotFound));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, to_bson, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    dtos::{requests::CreatePresentationDTO, responses::PresentationResponseDTO},
+    error::{AppError, PollsError},
+    models::presentation::{Presentation, PresentationPhase},
+};
+
+#[derive(Clone)]
+pub struct PresentationRepository {
+    presentations: Collection<Presentation>,
+}
+
+impl PresentationRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let presentations = db.collection::<Presentation>("presentations");
+        Self { presentations }
+    }
+
+    pub async fn create_presentation(
+        &self,
+        dto: CreatePresentationDTO,
+        created_by: String,
+    ) -> Result<PresentationResponseDTO, AppError> {
+        let presentation = Presentation {
+            presentationId: Uuid::new_v4().to_string(),
+            createdBy: created_by,
+            title: dto.title,
+            pollIds: dto.pollIds,
+            moderators: dto.moderators,
+            position: 0,
+            phase: PresentationPhase::Waiting,
+            createdAt: BsonDateTime::now(),
+        };
+
+        info!(
+            "Inserting new presentation to db {:#?}",
+            presentation.presentationId
+        );
+
+        self.presentations
+            .insert_one(&presentation)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(presentation.to_response_dto())
+    }
+
+    pub async fn get_presentations_of_user(
+        &self,
+        user_id: String,
+    ) -> Result<Vec<PresentationResponseDTO>, AppError> {
+        let presentations = self
+            .presentations
+            .find(doc! { "createdBy": user_id })
+            .sort(doc! { "createdAt": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Presentation>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .iter()
+            .map(|presentation| presentation.to_response_dto())
+            .collect();
+
+        Ok(presentations)
+    }
+
+    pub async fn get_presentation_by_id(
+        &self,
+        presentation_id: String,
+    ) -> Result<Presentation, AppError> {
+        self.presentations
+            .find_one(doc! { "presentationId": presentation_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::PresentationNotFound))
+    }
+
+    /// Moves the presenter's cursor to the poll at `position` in the given phase
+    pub async fn set_cursor(
+        &self,
+        presentation_id: &str,
+        position: i32,
+        phase: PresentationPhase,
+    ) -> Result<Presentation, AppError> {
+        let phase = to_bson(&phase).map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        self.presentations
+            .update_one(
+                doc! { "presentationId": presentation_id },
+                doc! { "$set": { "position": position, "phase": phase } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        self.get_presentation_by_id(presentation_id.to_string())
+            .await
+    }
+
+    /// Removes the presentation, its polls are kept
+    pub async fn delete_presentation(&self, presentation_id: String) -> Result<(), AppError> {
+        let delete_result = self
+            .presentations
+            .delete_one(doc! { "presentationId": presentation_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if delete_result.deleted_count == 0 {
+            return Err(AppError::Poll(PollsError::PresentationNotFound));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,137 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, to_bson, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    error::{AppError, PollsError},
+    models::question::{self, Question, QuestionStatus},
+};
+
+#[derive(Clone)]
+pub struct QuestionRepository {
+    questions: Collection<Question>,
+}
+
+impl QuestionRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let questions = db.collection::<Question>("questions");
+        Self { questions }
+    }
+
+    /// New questions wait for a moderator before the audience sees them
+    pub async fn create_question(
+        &self,
+        presentation_id: &str,
+        text: String,
+        asked_by: String,
+    ) -> Result<Question, AppError> {
+        let question = Question {
+            questionId: Uuid::new_v4().to_string(),
+            presentationId: presentation_id.to_string(),
+            text,
+            askedBy: asked_by,
+            status: QuestionStatus::Pending,
+            upvotes: 0,
+            upvotedBy: vec![],
+            createdAt: BsonDateTime::now(),
+        };
+
+        info!("Inserting new question to db {:#?}", question.questionId);
+
+        self.questions
+            .insert_one(&question)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(question)
+    }
+
+    pub async fn find_question(
+        &self,
+        presentation_id: &str,
+        question_id: &str,
+    ) -> Result<Question, AppError> {
+        self.questions
+            .find_one(doc! { "presentationId": presentation_id, "questionId": question_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::QuestionNotFound))
+    }
+
+    /// Every question of the presentation, ranked
+    pub async fn get_questions(&self, presentation_id: &str) -> Result<Vec<Question>, AppError> {
+        let mut questions = self
+            .questions
+            .find(doc! { "presentationId": presentation_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<Question>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        question::rank(&mut questions);
+        Ok(questions)
+    }
+
+    /// Fails if the user upvoted the question already, also when racing another request
+    pub async fn upvote(&self, question_id: &str, user_id: &str) -> Result<Question, AppError> {
+        self.questions
+            .find_one_and_update(
+                doc! { "questionId": question_id, "upvotedBy": { "$ne": user_id } },
+                doc! { "$inc": { "upvotes": 1 }, "$push": { "upvotedBy": user_id } },
+            )
+            .return_document(mongodb::options::ReturnDocument::After)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::AlreadyUpvoted))
+    }
+
+    /// Taking back an upvote that wasn't given changes nothing
+    pub async fn remove_upvote(&self, question_id: &str, user_id: &str) -> Result<(), AppError> {
+        self.questions
+            .update_one(
+                doc! { "questionId": question_id, "upvotedBy": user_id },
+                doc! { "$inc": { "upvotes": -1 }, "$pull": { "upvotedBy": user_id } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn set_status(
+        &self,
+        question_id: &str,
+        status: QuestionStatus,
+    ) -> Result<(), AppError> {
+        let status = to_bson(&status).map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        self.questions
+            .update_one(
+                doc! { "questionId": question_id },
+                doc! { "$set": { "status": status } },
+            )
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    pub async fn delete_questions_of_presentation(
+        &self,
+        presentation_id: &str,
+    ) -> Result<(), AppError> {
+        self.questions
+            .delete_many(doc! { "presentationId": presentation_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(())
+    }
+}
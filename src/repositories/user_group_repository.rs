@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use mongodb::{
+    bson::{doc, DateTime as BsonDateTime},
+    Collection,
+};
+use tracing::info;
+use uuid::Uuid;
+
+use crate::{
+    dtos::responses::UserGroupResponseDTO,
+    error::{AppError, PollsError},
+    models::user_group::UserGroup,
+};
+
+#[derive(Clone)]
+pub struct UserGroupRepository {
+    groups: Collection<UserGroup>,
+}
+
+impl UserGroupRepository {
+    pub fn new(db: Arc<mongodb::Database>) -> Self {
+        let groups = db.collection::<UserGroup>("user_groups");
+        Self { groups }
+    }
+
+    /// The creator starts out as the only member
+    pub async fn create_group(
+        &self,
+        name: String,
+        created_by: String,
+    ) -> Result<UserGroupResponseDTO, AppError> {
+        let group = UserGroup {
+            groupId: Uuid::new_v4().to_string(),
+            name,
+            createdBy: created_by.clone(),
+            members: vec![created_by],
+            createdAt: BsonDateTime::now(),
+        };
+
+        info!("Inserting new user group to db {:#?}", group.groupId);
+
+        self.groups
+            .insert_one(&group)
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        Ok(group.to_response_dto())
+    }
+
+    /// Groups the user manages or belongs to
+    pub async fn get_groups_of_user(
+        &self,
+        user_id: &str,
+    ) -> Result<Vec<UserGroupResponseDTO>, AppError> {
+        let groups = self
+            .groups
+            .find(doc! { "$or": [{ "createdBy": user_id }, { "members": user_id }] })
+            .sort(doc! { "createdAt": -1 })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .try_collect::<Vec<UserGroup>>()
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .iter()
+            .map(|group| group.to_response_dto())
+            .collect();
+
+        Ok(groups)
+    }
+
+    /// Fetches a group, only its creator is allowed to manage it
+    pub async fn get_group_of_owner(
+        &self,
+        group_id: &str,
+        user_id: &str,
+    ) -> Result<UserGroup, AppError> {
+        let group = self
+            .groups
+            .find_one(doc! { "groupId": group_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?
+            .ok_or(AppError::Poll(PollsError::GroupNotFound))?;
+
+        if group.createdBy != user_id {
+            return Err(AppError::Poll(PollsError::Unauthorized));
+        }
+
+        Ok(group)
+    }
+
+    /// Polls may only target groups their editor manages
+    pub async fn verify_groups_of_owner(
+        &self,
+        group_ids: &[String],
+        user_id: &str,
+    ) -> Result<(), AppError> {
+        for group_id in group_ids {
+            self.get_group_of_owner(group_id, user_id).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn add_member(&self, group_id: &str, user_id: &str) -> Result<(), AppError> {
+        self.groups
+            .update_one(
+                doc! { "groupId": group_id },
+                doc! { "$addToSet": { "members": user_id } },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    pub async fn remove_member(&self, group_id: &str, user_id: &str) -> Result<(), AppError> {
+        self.groups
+            .update_one(
+                doc! { "groupId": group_id },
+                doc! { "$pull": { "members": user_id } },
+            )
+            .await
+            .map(|_| ())
+            .map_err(|e| AppError::DatabaseError(e.to_string()))
+    }
+
+    pub async fn delete_group(&self, group_id: &str) -> Result<(), AppError> {
+        let delete_result = self
+            .groups
+            .delete_one(doc! { "groupId": group_id })
+            .await
+            .map_err(|e| AppError::DatabaseError(e.to_string()))?;
+
+        if delete_result.deleted_count == 0 {
+            return Err(AppError::Poll(PollsError::GroupNotFound));
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,5 @@
 use anyhow::Error;
+use futures::TryStreamExt;
 use std::sync::Arc;
 use webauthn_rs::prelude::Passkey;
 
@@ -34,6 +35,16 @@ impl UserRepository {
         Ok(user)
     }
 
+    pub async fn get_users_by_ids(&self, user_ids: &[String]) -> Result<Vec<User>, Error> {
+        let users = self
+            .collection
+            .find(doc! { "user_id": { "$in": user_ids } })
+            .await?
+            .try_collect::<Vec<User>>()
+            .await?;
+        Ok(users)
+    }
+
     pub async fn update_user(
         &self,
         updated_credentials: Vec<Passkey>,
@@ -1,2 +1,7 @@
 pub mod auth_route;
+pub mod organisation_route;
 pub mod poll_route;
+pub mod presentation_route;
+pub mod series_route;
+pub mod template_route;
+pub mod user_group_route;
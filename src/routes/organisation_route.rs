@@ -0,0 +1,48 @@
+use axum::{
+    routing::{get, patch, post},
+    Router,
+};
+
+use crate::{
+    controllers::organisation_controller::{
+        create_organisation, delete_organisation_by_id, get_organisation_by_id,
+        get_organisation_polls, get_user_organisations, join_organisation, remove_member,
+        rotate_organisation_join_code, update_member_role,
+    },
+    middleware::auth::require_auth,
+};
+
+pub fn organisation_router() -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_user_organisations)
+                .post(create_organisation)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/join",
+            post(join_organisation).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{org_id}",
+            get(get_organisation_by_id)
+                .delete(delete_organisation_by_id)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{org_id}/join-code",
+            post(rotate_organisation_join_code)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{org_id}/members/{user_id}",
+            patch(update_member_role)
+                .delete(remove_member)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{org_id}/polls",
+            get(get_organisation_polls).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+}
@@ -1,14 +1,31 @@
 use axum::{
-    routing::{get, patch, post},
+    routing::{delete, get, patch, post},
     Router,
 };
 
 use crate::{
+    controllers::chart_controller::get_poll_results_chart,
+    controllers::collaborator_controller::{
+        accept_collaboration, decline_collaboration, get_collaboration_invitations,
+        get_poll_collaborators, invite_collaborator, remove_collaborator,
+    },
+    controllers::comment_controller::{
+        delete_poll_comment, edit_poll_comment, get_comment_replies, get_poll_comments,
+        post_poll_comment,
+    },
+    controllers::guest_controller::{can_guest_vote, cast_guest_vote, issue_guest_token},
+    controllers::live_controller::live_polls_socket,
+    controllers::password_controller::{remove_poll_password, set_poll_password, unlock_poll},
     controllers::poll_controller::{
-        can_user_vote, cast_vote, close_poll_by_id, create_new_poll, get_all_polls, get_poll_by_id,
-        get_poll_live_results, get_poll_result, manage_all_polls, reset_poll_by_id,
+        allow_poll_user, can_user_vote, cast_vote, clone_poll_by_id, close_poll_by_id,
+        create_new_poll, get_all_polls, get_poll_access, get_poll_analytics, get_poll_by_id,
+        get_poll_by_join_code, get_poll_live_results, get_poll_result, get_poll_revision_diff,
+        get_poll_revisions, get_poll_voters, issue_live_stream_token, manage_all_polls,
+        pause_poll_by_id, redeem_invite_code, regenerate_join_code, reset_poll_by_id,
+        resume_poll_by_id, revoke_poll_user, rotate_poll_invite_code, send_poll_reaction,
         update_poll_by_id,
     },
+    controllers::qr_controller::{get_join_code_qr_code, get_poll_qr_code},
     middleware::auth::require_auth,
 };
 
@@ -46,11 +63,130 @@ pub fn poll_router() -> Router {
             "/{poll_id}/close",
             get(close_poll_by_id).route_layer(axum::middleware::from_fn(require_auth)),
         )
+        .route(
+            "/{poll_id}/pause",
+            post(pause_poll_by_id).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/resume",
+            post(resume_poll_by_id).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/clone",
+            post(clone_poll_by_id).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/access",
+            get(get_poll_access).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/access/redeem",
+            post(redeem_invite_code).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/access/users",
+            post(allow_poll_user).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/access/users/{user_id}",
+            delete(revoke_poll_user).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/access/rotate",
+            post(rotate_poll_invite_code).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/join-code",
+            post(regenerate_join_code).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/join/{join_code}",
+            get(get_poll_by_join_code).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/revisions",
+            get(get_poll_revisions).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/revisions/diff",
+            get(get_poll_revision_diff).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/voters",
+            get(get_poll_voters).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/reactions",
+            post(send_poll_reaction).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/results/live/token",
+            post(issue_live_stream_token).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/analytics",
+            get(get_poll_analytics).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/comments",
+            get(get_poll_comments)
+                .post(post_poll_comment)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/comments/{comment_id}",
+            patch(edit_poll_comment)
+                .delete(delete_poll_comment)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/comments/{comment_id}/replies",
+            get(get_comment_replies).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/password",
+            post(set_poll_password)
+                .delete(remove_poll_password)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/collaborators",
+            get(get_poll_collaborators)
+                .post(invite_collaborator)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/collaborators/{user_id}",
+            delete(remove_collaborator).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/collaborators/accept",
+            post(accept_collaboration).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{poll_id}/collaborators/decline",
+            post(decline_collaboration).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/invitations",
+            get(get_collaboration_invitations).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/ws",
+            get(live_polls_socket).route_layer(axum::middleware::from_fn(require_auth)),
+        )
         .route(
             "/manage",
             get(manage_all_polls).route_layer(axum::middleware::from_fn(require_auth)),
         )
         // public routes
+        .route("/{poll_id}/unlock", post(unlock_poll))
+        .route("/{poll_id}/guest-token", post(issue_guest_token))
+        .route("/{poll_id}/guest-vote", get(cast_guest_vote))
+        .route("/{poll_id}/guest-can-vote", get(can_guest_vote))
+        .route("/{poll_id}/qr", get(get_poll_qr_code))
+        .route("/join/{join_code}/qr", get(get_join_code_qr_code))
         .route("/{poll_id}/results", get(get_poll_result))
         .route("/{poll_id}/results/live", get(get_poll_live_results))
+        .route("/{poll_id}/results/chart", get(get_poll_results_chart))
 }
@@ -0,0 +1,80 @@
+use axum::{
+    routing::{get, patch, post},
+    Router,
+};
+
+use crate::{
+    controllers::presentation_controller::{
+        create_presentation, delete_presentation, end_presentation, get_presentation_by_id,
+        get_presentation_stream, get_user_presentations, lock_presentation_poll,
+        next_presentation_poll, previous_presentation_poll, reveal_presentation_poll,
+        start_presentation,
+    },
+    controllers::question_controller::{
+        ask_question, get_question_stream, get_questions, moderate_question,
+        remove_question_upvote, upvote_question,
+    },
+    middleware::auth::require_auth,
+};
+
+pub fn presentation_router() -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_user_presentations)
+                .post(create_presentation)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}",
+            get(get_presentation_by_id)
+                .delete(delete_presentation)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/start",
+            post(start_presentation).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/next",
+            post(next_presentation_poll).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/previous",
+            post(previous_presentation_poll).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/lock",
+            post(lock_presentation_poll).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/reveal",
+            post(reveal_presentation_poll).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/end",
+            post(end_presentation).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/questions",
+            get(get_questions)
+                .post(ask_question)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/questions/{question_id}",
+            patch(moderate_question).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{presentation_id}/questions/{question_id}/upvote",
+            post(upvote_question)
+                .delete(remove_question_upvote)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        // public routes
+        .route("/{presentation_id}/stream", get(get_presentation_stream))
+        .route(
+            "/{presentation_id}/questions/live",
+            get(get_question_stream),
+        )
+}
@@ -0,0 +1,40 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::{
+    controllers::series_controller::{
+        create_series, delete_series, get_series_by_id, get_series_trends, get_user_series,
+        pause_series, resume_series,
+    },
+    middleware::auth::require_auth,
+};
+
+pub fn series_router() -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_user_series)
+                .post(create_series)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{series_id}",
+            get(get_series_by_id)
+                .delete(delete_series)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{series_id}/pause",
+            post(pause_series).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{series_id}/resume",
+            post(resume_series).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{series_id}/trends",
+            get(get_series_trends).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+}
@@ -0,0 +1,32 @@
+use axum::{
+    routing::{get, post},
+    Router,
+};
+
+use crate::{
+    controllers::template_controller::{
+        create_template, delete_template_by_id, get_template_by_id, get_user_templates,
+        instantiate_template,
+    },
+    middleware::auth::require_auth,
+};
+
+pub fn template_router() -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_user_templates)
+                .post(create_template)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{template_id}",
+            get(get_template_by_id)
+                .delete(delete_template_by_id)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{template_id}/instantiate",
+            post(instantiate_template).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+}
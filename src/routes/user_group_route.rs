@@ -0,0 +1,36 @@
+use axum::{
+    routing::{delete, get, post},
+    Router,
+};
+
+use crate::{
+    controllers::user_group_controller::{
+        add_group_member, create_user_group, delete_user_group_by_id, get_user_group_by_id,
+        get_user_groups, remove_group_member,
+    },
+    middleware::auth::require_auth,
+};
+
+pub fn user_group_router() -> Router {
+    Router::new()
+        .route(
+            "/",
+            get(get_user_groups)
+                .post(create_user_group)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{group_id}",
+            get(get_user_group_by_id)
+                .delete(delete_user_group_by_id)
+                .route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{group_id}/members",
+            post(add_group_member).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+        .route(
+            "/{group_id}/members/{user_id}",
+            delete(remove_group_member).route_layer(axum::middleware::from_fn(require_auth)),
+        )
+}
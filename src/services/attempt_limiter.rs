@@ -0,0 +1,113 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use tracing::warn;
+
+use crate::error::AppError;
+
+/// Counts attempts per key in a sliding window, used to slow down password guessing and
+/// reaction spam
+#[derive(Clone)]
+pub struct AttemptLimiter {
+    attempts: Arc<Mutex<Attempts>>,
+    max_attempts: usize,
+    window: Duration,
+}
+
+struct Attempts {
+    by_key: HashMap<String, VecDeque<Instant>>,
+    swept_at: Instant, // Last time expired keys were dropped
+}
+
+impl AttemptLimiter {
+    pub fn new(max_attempts: usize, window: Duration) -> Self {
+        Self {
+            attempts: Arc::new(Mutex::new(Attempts {
+                by_key: HashMap::new(),
+                swept_at: Instant::now(),
+            })),
+            max_attempts,
+            window,
+        }
+    }
+
+    // Reads the limits from the environment
+    fn from_env(max_key: &str, max_default: u64, window_key: &str, window_default: u64) -> Self {
+        let read = |key: &str, default: u64| {
+            std::env::var(key)
+                .ok()
+                .and_then(|value| value.parse::<u64>().ok())
+                .unwrap_or(default)
+        };
+
+        Self::new(
+            read(max_key, max_default) as usize,
+            Duration::from_secs(read(window_key, window_default)),
+        )
+    }
+
+    /// Reads POLL_UNLOCK_MAX_ATTEMPTS (default 5) and POLL_UNLOCK_WINDOW_SECS (default 900)
+    pub fn for_poll_unlock() -> Self {
+        Self::from_env(
+            "POLL_UNLOCK_MAX_ATTEMPTS",
+            5,
+            "POLL_UNLOCK_WINDOW_SECS",
+            900,
+        )
+    }
+
+    /// Reads GUEST_TOKENS_MAX (default 10) and GUEST_TOKENS_WINDOW_SECS (default 3600)
+    pub fn for_guest_tokens() -> Self {
+        Self::from_env("GUEST_TOKENS_MAX", 10, "GUEST_TOKENS_WINDOW_SECS", 3600)
+    }
+
+    /// Reads POLL_REACTIONS_MAX (default 10) and POLL_REACTIONS_WINDOW_SECS (default 10)
+    pub fn for_reactions() -> Self {
+        Self::from_env("POLL_REACTIONS_MAX", 10, "POLL_REACTIONS_WINDOW_SECS", 10)
+    }
+
+    /// Counts an attempt for the key, or fails with `RateLimitExceeded` once it used up its
+    /// attempts. Checking and counting happen under one lock, so concurrent attempts can't
+    /// all slip through.
+    pub fn attempt(&self, key: &str) -> Result<(), AppError> {
+        let mut attempts = self.attempts.lock().unwrap();
+        let now = Instant::now();
+
+        // Keys nobody tries anymore are dropped once per window, so the map doesn't grow
+        // forever without going through it on every attempt
+        if now.duration_since(attempts.swept_at) > self.window {
+            attempts.by_key.retain(|_, times| {
+                self.expire(times, now);
+                !times.is_empty()
+            });
+            attempts.swept_at = now;
+        }
+
+        let times = attempts.by_key.entry(key.to_string()).or_default();
+        self.expire(times, now);
+        if times.len() >= self.max_attempts {
+            warn!("Too many attempts for {}", key);
+            return Err(AppError::RateLimitExceeded);
+        }
+        times.push_back(now);
+
+        Ok(())
+    }
+
+    pub fn reset(&self, key: &str) {
+        self.attempts.lock().unwrap().by_key.remove(key);
+    }
+
+    // Drops the attempts that left the window, oldest first
+    fn expire(&self, times: &mut VecDeque<Instant>, now: Instant) {
+        while times
+            .front()
+            .is_some_and(|at| now.duration_since(*at) > self.window)
+        {
+            times.pop_front();
+        }
+    }
+}
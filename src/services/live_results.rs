@@ -0,0 +1,585 @@
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use chrono::Utc;
+use futures::{Stream, TryStreamExt};
+use mongodb::{bson::doc, options::FullDocumentType, Database};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{error, info, warn};
+
+use crate::{
+    dtos::responses::{LiveEvent, LiveResults, LiveUpdate, OptionVoteDelta, PollResponseDTO},
+    models::poll::{Poll, Reaction},
+};
+
+const CHANNEL_CAPACITY: usize = 64;
+// Events kept per poll for viewers resuming a stream
+const HISTORY_SIZE: usize = 256;
+// How long a poll nobody watches keeps its history, long enough to reconnect
+const IDLE_RETENTION: Duration = Duration::from_secs(300);
+// Viewers coming and going within this window are announced as a single count
+const VIEWER_COUNT_DEBOUNCE: Duration = Duration::from_secs(2);
+// Reactions are summed up over this long into a single event
+const REACTIONS_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Fans out the events of a poll to all its live viewers from a single source. Events are
+/// derived from the state a mutation left the poll in, so publishing a state that didn't
+/// change is a no-op and several sources can feed the same poll.
+#[derive(Clone)]
+pub struct LiveResultsHub {
+    polls: Arc<Mutex<HashMap<String, PollChannel>>>,
+    last_seq: Arc<AtomicU64>,
+}
+
+struct PollChannel {
+    sender: broadcast::Sender<Arc<LiveUpdate>>,
+    state: Option<LiveResults>, // Last published state
+    history: VecDeque<Arc<LiveUpdate>>,
+    floor: u64, // Events up to this sequence number aren't in the history
+    last_active: Instant,
+    viewers: usize,
+    announced_viewers: usize, // Count sent with the last viewer-count event
+    announcement_pending: bool,
+    reactions: BTreeMap<Reaction, u32>, // Received since the last reactions event
+}
+
+pub struct LiveSubscription {
+    pub receiver: broadcast::Receiver<Arc<LiveUpdate>>,
+    /// Sequence number of the latest event when subscribing
+    pub seq: u64,
+    /// Events after the one the viewer saw last, `None` when they aren't all kept anymore
+    pub missed: Option<Vec<Arc<LiveUpdate>>>,
+    /// Viewers of the poll, this one included
+    pub viewers: usize,
+    viewer: ViewerGuard,
+}
+
+/// Counts a viewer for as long as it is alive
+struct ViewerGuard {
+    hub: LiveResultsHub,
+    poll_id: String,
+}
+
+impl Drop for ViewerGuard {
+    fn drop(&mut self) {
+        let mut polls = self.hub.polls.lock().unwrap();
+        if let Some(channel) = polls.get_mut(&self.poll_id) {
+            channel.viewers = channel.viewers.saturating_sub(1);
+            channel.last_active = Instant::now();
+            self.hub.schedule_viewer_count(&self.poll_id, channel);
+        }
+    }
+}
+
+impl Default for LiveResultsHub {
+    fn default() -> Self {
+        // Starting from the clock keeps sequence numbers growing across restarts
+        Self {
+            polls: Arc::default(),
+            last_seq: Arc::new(AtomicU64::new(Utc::now().timestamp_millis() as u64)),
+        }
+    }
+}
+
+impl LiveResultsHub {
+    /// Sends viewers of the poll the events leading to its new state
+    pub fn publish(&self, poll: &PollResponseDTO) {
+        let mut polls = self.polls.lock().unwrap();
+        // Nobody is watching, the next viewer reads the counts from the database anyway
+        let Some(channel) = polls.get_mut(&poll.poll_id) else {
+            return;
+        };
+
+        let state = LiveResults::from(poll);
+        let events = match &channel.state {
+            Some(previous) => diff_events(previous, &state),
+            // Viewers got their counts from the database, the first update replaces them
+            None => vec![LiveEvent::Snapshot(state.clone())],
+        };
+        channel.state = Some(state);
+
+        for event in events {
+            self.send(channel, &poll.poll_id, event);
+        }
+        channel.last_active = Instant::now();
+    }
+
+    /// Viewers currently connected to the live results of the poll
+    pub fn viewers(&self, poll_id: &str) -> usize {
+        self.polls
+            .lock()
+            .unwrap()
+            .get(poll_id)
+            .map_or(0, |channel| channel.viewers)
+    }
+
+    /// Subscribe before reading the current counts, so no event falls in between.
+    /// `last_seq` is the event a reconnecting viewer saw last. The subscriber counts as a
+    /// viewer until its updates stream is dropped.
+    pub fn subscribe(&self, poll_id: &str, last_seq: Option<u64>) -> LiveSubscription {
+        let mut polls = self.polls.lock().unwrap();
+        // Drop the channels of polls nobody watched for a while
+        polls.retain(|_, channel| {
+            channel.viewers > 0 || channel.last_active.elapsed() < IDLE_RETENTION
+        });
+
+        let channel = polls
+            .entry(poll_id.to_string())
+            .or_insert_with(|| PollChannel {
+                sender: broadcast::channel(CHANNEL_CAPACITY).0,
+                state: None,
+                history: VecDeque::new(),
+                floor: self.last_seq.load(Ordering::SeqCst),
+                last_active: Instant::now(),
+                viewers: 0,
+                announced_viewers: 0,
+                announcement_pending: false,
+                reactions: BTreeMap::new(),
+            });
+        channel.last_active = Instant::now();
+        channel.viewers += 1;
+        self.schedule_viewer_count(poll_id, channel);
+
+        let seq = channel
+            .history
+            .back()
+            .map_or(channel.floor, |update| update.seq);
+        let missed = last_seq
+            .filter(|last_seq| (channel.floor..=seq).contains(last_seq))
+            .map(|last_seq| {
+                channel
+                    .history
+                    .iter()
+                    .filter(|update| update.seq > last_seq)
+                    .cloned()
+                    .collect()
+            });
+
+        LiveSubscription {
+            receiver: channel.sender.subscribe(),
+            seq,
+            missed,
+            viewers: channel.viewers,
+            viewer: ViewerGuard {
+                hub: self.clone(),
+                poll_id: poll_id.to_string(),
+            },
+        }
+    }
+
+    /// Events of a poll published after the subscription. Viewers falling too far behind
+    /// get a snapshot and continue from there.
+    pub fn updates(
+        &self,
+        subscription: LiveSubscription,
+        poll_id: &str,
+    ) -> impl Stream<Item = Arc<LiveUpdate>> + Send + 'static {
+        let hub = self.clone();
+        let poll_id = poll_id.to_string();
+        let LiveSubscription {
+            receiver,
+            seq,
+            viewer,
+            ..
+        } = subscription;
+
+        // The guard lives in the stream state, the viewer leaves once the stream is dropped
+        futures::stream::unfold(
+            (receiver, seq, viewer),
+            move |(mut receiver, after_seq, viewer)| {
+                let hub = hub.clone();
+                let poll_id = poll_id.clone();
+                async move {
+                    loop {
+                        match receiver.recv().await {
+                            Ok(update) if update.seq > after_seq => {
+                                let seq = update.seq;
+                                return Some((update, (receiver, seq, viewer)));
+                            }
+                            // Already sent while catching up
+                            Ok(_) => continue,
+                            Err(RecvError::Lagged(_)) => {
+                                if let Some(snapshot) = hub.snapshot(&poll_id) {
+                                    let seq = snapshot.seq;
+                                    return Some((snapshot, (receiver, seq, viewer)));
+                                }
+                            }
+                            Err(RecvError::Closed) => return None,
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    // Sends the viewer count once it settled, unless it ends up where it was
+    fn schedule_viewer_count(&self, poll_id: &str, channel: &mut PollChannel) {
+        if channel.announcement_pending {
+            return;
+        }
+        channel.announcement_pending = true;
+
+        let hub = self.clone();
+        let poll_id = poll_id.to_string();
+        tokio::spawn(async move {
+            tokio::time::sleep(VIEWER_COUNT_DEBOUNCE).await;
+
+            let mut polls = hub.polls.lock().unwrap();
+            let Some(channel) = polls.get_mut(&poll_id) else {
+                return;
+            };
+            channel.announcement_pending = false;
+            if channel.viewers != channel.announced_viewers {
+                channel.announced_viewers = channel.viewers;
+                let viewers = channel.viewers;
+                hub.send(channel, &poll_id, LiveEvent::ViewerCount { viewers });
+            }
+        });
+    }
+
+    /// Sends an event that isn't part of the poll's state to its viewers, if it has any
+    pub fn notify(&self, poll_id: &str, event: LiveEvent) {
+        let mut polls = self.polls.lock().unwrap();
+        if let Some(channel) = polls.get_mut(poll_id) {
+            self.send(channel, poll_id, event);
+        }
+    }
+
+    /// Counts the reaction towards the next reactions event of the poll. Reactions to polls
+    /// nobody watches are dropped.
+    pub fn react(&self, poll_id: &str, reaction: Reaction) {
+        let mut polls = self.polls.lock().unwrap();
+        let Some(channel) = polls.get_mut(poll_id) else {
+            return;
+        };
+        let interval_started = !channel.reactions.is_empty();
+        *channel.reactions.entry(reaction).or_default() += 1;
+        if interval_started {
+            return;
+        }
+
+        let hub = self.clone();
+        let poll_id = poll_id.to_string();
+        tokio::spawn(async move {
+            tokio::time::sleep(REACTIONS_INTERVAL).await;
+
+            let mut polls = hub.polls.lock().unwrap();
+            let Some(channel) = polls.get_mut(&poll_id) else {
+                return;
+            };
+            let counts = std::mem::take(&mut channel.reactions);
+            if !counts.is_empty() {
+                hub.send(channel, &poll_id, LiveEvent::Reactions { counts });
+            }
+        });
+    }
+
+    // Numbers the event, keeps it for resuming viewers and sends it to the connected ones
+    fn send(&self, channel: &mut PollChannel, poll_id: &str, event: LiveEvent) {
+        let update = Arc::new(LiveUpdate::new(self.next_seq(), poll_id.to_string(), event));
+        channel.history.push_back(update.clone());
+        if channel.history.len() > HISTORY_SIZE {
+            if let Some(evicted) = channel.history.pop_front() {
+                channel.floor = evicted.seq;
+            }
+        }
+        let _ = channel.sender.send(update);
+    }
+
+    // Last published state of the poll, as of its latest event
+    fn snapshot(&self, poll_id: &str) -> Option<Arc<LiveUpdate>> {
+        let polls = self.polls.lock().unwrap();
+        let channel = polls.get(poll_id)?;
+        let seq = channel.history.back()?.seq;
+
+        Some(Arc::new(LiveUpdate::new(
+            seq,
+            poll_id.to_string(),
+            LiveEvent::Snapshot(channel.state.clone()?),
+        )))
+    }
+
+    fn next_seq(&self) -> u64 {
+        self.last_seq.fetch_add(1, Ordering::SeqCst) + 1
+    }
+}
+
+// Events turning `previous` into `current`, empty if nothing changed
+fn diff_events(previous: &LiveResults, current: &LiveResults) -> Vec<LiveEvent> {
+    // Reopening isn't an event of its own, clients start over from the new state
+    if previous.is_closed && !current.is_closed {
+        return vec![LiveEvent::Snapshot(current.clone())];
+    }
+
+    let mut events = Vec::new();
+    let previous_option = |option_id: &str| {
+        previous
+            .options
+            .iter()
+            .find(|option| option.option_id == option_id)
+    };
+
+    let votes_dropped = current.options.iter().any(|option| {
+        previous_option(&option.option_id).is_some_and(|before| option.votes < before.votes)
+    });
+    let is_reset = votes_dropped
+        && current
+            .options
+            .iter()
+            .all(|option| option.votes.unwrap_or_default() == 0);
+    if is_reset {
+        events.push(LiveEvent::Reset {
+            options: current.options.clone(),
+        });
+    }
+
+    for option in &previous.options {
+        if !current
+            .options
+            .iter()
+            .any(|current| current.option_id == option.option_id)
+        {
+            events.push(LiveEvent::OptionRemoved {
+                option_id: option.option_id.clone(),
+            });
+        }
+    }
+
+    let mut changes = Vec::new();
+    for option in &current.options {
+        match previous_option(&option.option_id) {
+            None => events.push(LiveEvent::OptionAdded {
+                option: option.clone(),
+            }),
+            Some(before) => {
+                if before.option_name != option.option_name
+                    || before.display_order != option.display_order
+                {
+                    events.push(LiveEvent::OptionUpdated {
+                        option: option.clone(),
+                    });
+                }
+
+                let delta = option.votes.unwrap_or_default() - before.votes.unwrap_or_default();
+                if delta != 0 && !is_reset {
+                    changes.push(OptionVoteDelta {
+                        option_id: option.option_id.clone(),
+                        delta,
+                        votes: option.votes.unwrap_or_default(),
+                    });
+                }
+            }
+        }
+    }
+    if !changes.is_empty() {
+        events.push(LiveEvent::VoteDelta { changes });
+    }
+
+    match (previous.is_paused, current.is_paused) {
+        (false, true) => events.push(LiveEvent::Paused),
+        (true, false) => events.push(LiveEvent::Resumed),
+        _ => {}
+    }
+    if !previous.is_closed && current.is_closed {
+        events.push(LiveEvent::Closed);
+    }
+
+    events
+}
+
+/// Publishes poll updates made by other instances of the API, enabled with
+/// `LIVE_RESULTS_CHANGE_STREAM=true`. Needs MongoDB running as a replica set.
+pub async fn run_change_stream_listener(db: Arc<Database>, hub: LiveResultsHub) {
+    let enabled = std::env::var("LIVE_RESULTS_CHANGE_STREAM")
+        .map(|value| value == "true")
+        .unwrap_or(false);
+    if !enabled {
+        return;
+    }
+
+    let mut change_stream = match db
+        .collection::<Poll>("polls")
+        .watch()
+        .pipeline([doc! { "$match": { "operationType": { "$in": ["update", "replace"] } } }])
+        .full_document(FullDocumentType::UpdateLookup)
+        .await
+    {
+        Ok(change_stream) => change_stream,
+        Err(e) => {
+            error!("Failed to watch polls for live results: {}", e);
+            return;
+        }
+    };
+
+    info!("Live results change stream started");
+
+    loop {
+        match change_stream.try_next().await {
+            Ok(Some(event)) => {
+                if let Some(poll) = event.full_document {
+                    hub.publish(&poll.to_response_dto());
+                }
+            }
+            Ok(None) => break,
+            Err(e) => {
+                error!("Live results change stream failed: {}", e);
+                break;
+            }
+        }
+    }
+
+    warn!("Live results change stream stopped");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dtos::responses::PollOptionResponseDTO;
+
+    fn option(option_id: &str, votes: i32, display_order: i32) -> PollOptionResponseDTO {
+        PollOptionResponseDTO {
+            option_id: option_id.to_string(),
+            option_name: option_id.to_uppercase(),
+            votes: Some(votes),
+            display_order,
+        }
+    }
+
+    fn results(options: Vec<PollOptionResponseDTO>) -> LiveResults {
+        LiveResults {
+            poll_id: "poll".to_string(),
+            options,
+            is_paused: false,
+            is_closed: false,
+        }
+    }
+
+    #[test]
+    fn unchanged_results_have_no_events() {
+        let state = results(vec![option("a", 3, 0), option("b", 1, 1)]);
+
+        assert!(diff_events(&state, &state.clone()).is_empty());
+    }
+
+    #[test]
+    fn votes_become_one_delta_event() {
+        let previous = results(vec![option("a", 3, 0), option("b", 1, 1)]);
+        let current = results(vec![option("a", 4, 0), option("b", 0, 1)]);
+
+        assert_eq!(
+            diff_events(&previous, &current),
+            vec![LiveEvent::VoteDelta {
+                changes: vec![
+                    OptionVoteDelta {
+                        option_id: "a".to_string(),
+                        delta: 1,
+                        votes: 4,
+                    },
+                    OptionVoteDelta {
+                        option_id: "b".to_string(),
+                        delta: -1,
+                        votes: 0,
+                    },
+                ],
+            }]
+        );
+    }
+
+    #[test]
+    fn clearing_all_votes_is_a_reset_without_deltas() {
+        let previous = results(vec![option("a", 3, 0), option("b", 1, 1)]);
+        let current = results(vec![option("a", 0, 0), option("b", 0, 1)]);
+
+        assert_eq!(
+            diff_events(&previous, &current),
+            vec![LiveEvent::Reset {
+                options: current.options.clone(),
+            }]
+        );
+    }
+
+    #[test]
+    fn option_edits_are_reported_per_option() {
+        let previous = results(vec![option("a", 3, 0), option("b", 1, 1)]);
+        let mut renamed = option("a", 3, 1);
+        renamed.option_name = "Renamed".to_string();
+        let current = results(vec![renamed.clone(), option("c", 0, 0)]);
+
+        assert_eq!(
+            diff_events(&previous, &current),
+            vec![
+                LiveEvent::OptionRemoved {
+                    option_id: "b".to_string(),
+                },
+                LiveEvent::OptionUpdated { option: renamed },
+                LiveEvent::OptionAdded {
+                    option: option("c", 0, 0),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn state_changes_follow_the_vote_deltas() {
+        let previous = results(vec![option("a", 3, 0)]);
+        let mut current = results(vec![option("a", 4, 0)]);
+        current.is_paused = true;
+        current.is_closed = true;
+
+        assert_eq!(
+            diff_events(&previous, &current),
+            vec![
+                LiveEvent::VoteDelta {
+                    changes: vec![OptionVoteDelta {
+                        option_id: "a".to_string(),
+                        delta: 1,
+                        votes: 4,
+                    }],
+                },
+                LiveEvent::Paused,
+                LiveEvent::Closed,
+            ]
+        );
+
+        let mut resumed = current.clone();
+        resumed.is_paused = false;
+        assert_eq!(diff_events(&current, &resumed), vec![LiveEvent::Resumed]);
+    }
+
+    #[test]
+    fn reopening_sends_a_fresh_snapshot() {
+        let mut previous = results(vec![option("a", 3, 0)]);
+        previous.is_closed = true;
+        let current = results(vec![option("a", 0, 0), option("b", 0, 1)]);
+
+        assert_eq!(
+            diff_events(&previous, &current),
+            vec![LiveEvent::Snapshot(current.clone())]
+        );
+    }
+
+    #[test]
+    fn hidden_counts_count_as_zero() {
+        let mut hidden = option("a", 0, 0);
+        hidden.votes = None;
+        let previous = results(vec![hidden]);
+        let current = results(vec![option("a", 2, 0)]);
+
+        assert_eq!(
+            diff_events(&previous, &current),
+            vec![LiveEvent::VoteDelta {
+                changes: vec![OptionVoteDelta {
+                    option_id: "a".to_string(),
+                    delta: 2,
+                    votes: 2,
+                }],
+            }]
+        );
+    }
+}
@@ -0,0 +1,4 @@
+pub mod attempt_limiter;
+pub mod live_results;
+pub mod presentation_hub;
+pub mod series_scheduler;
@@ -0,0 +1,75 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use futures::Stream;
+use tokio::sync::broadcast::{self, error::RecvError};
+
+use crate::dtos::responses::{PresentationStateDTO, QuestionBoardDTO};
+
+// Presenter actions are far apart, a small buffer is plenty
+const CHANNEL_CAPACITY: usize = 16;
+
+/// Pushes what the audience of a presentation sees whenever it changes
+#[derive(Clone, Default)]
+pub struct PresentationHub {
+    pub states: Channels<PresentationStateDTO>, // After every presenter action
+    pub questions: Channels<QuestionBoardDTO>,  // Ranked Q&A, after every question change
+}
+
+/// Broadcasts per presentation. Every message is complete, so viewers falling behind just
+/// skip to the newer ones.
+pub struct Channels<T> {
+    senders: Arc<Mutex<HashMap<String, broadcast::Sender<Arc<T>>>>>,
+}
+
+impl<T> Default for Channels<T> {
+    fn default() -> Self {
+        Self {
+            senders: Arc::default(),
+        }
+    }
+}
+
+impl<T> Clone for Channels<T> {
+    fn clone(&self) -> Self {
+        Self {
+            senders: self.senders.clone(),
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Channels<T> {
+    pub fn publish(&self, presentation_id: &str, message: T) {
+        let senders = self.senders.lock().unwrap();
+        if let Some(sender) = senders.get(presentation_id) {
+            let _ = sender.send(Arc::new(message));
+        }
+    }
+
+    /// Messages published from now on. Subscribe before reading the current value, so no
+    /// change falls in between.
+    pub fn subscribe(&self, presentation_id: &str) -> impl Stream<Item = Arc<T>> + Send + 'static {
+        let receiver = {
+            let mut senders = self.senders.lock().unwrap();
+            // Drop the channels of presentations nobody watches anymore
+            senders.retain(|_, sender| sender.receiver_count() > 0);
+
+            senders
+                .entry(presentation_id.to_string())
+                .or_insert_with(|| broadcast::channel(CHANNEL_CAPACITY).0)
+                .subscribe()
+        };
+
+        futures::stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(message) => return Some((message, receiver)),
+                    Err(RecvError::Lagged(_)) => continue,
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+}
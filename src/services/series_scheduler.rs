@@ -0,0 +1,124 @@
+use std::{collections::HashMap, sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use mongodb::Database;
+use tracing::{error, info, warn};
+
+use crate::{
+    dtos::requests::CreatePollDTO,
+    error::{AppError, PollsError},
+    models::{poll::ResultsVisibility, poll_series::PollSeries},
+    repositories::{
+        poll_repository::PollRepository, poll_revision_repository::PollRevisionRepository,
+        poll_series_repository::PollSeriesRepository,
+    },
+    utils::{recurrence, template},
+};
+
+/// Background task generating the polls of recurring series once they are due.
+/// Runs forever, the check interval can be tuned with `SERIES_SCHEDULER_INTERVAL_SECS`.
+pub async fn run_series_scheduler(db: Arc<Database>) {
+    let interval_secs = std::env::var("SERIES_SCHEDULER_INTERVAL_SECS")
+        .ok()
+        .and_then(|secs| secs.parse::<u64>().ok())
+        .unwrap_or(60);
+
+    info!(
+        "Series scheduler started, checking every {}s",
+        interval_secs
+    );
+
+    let series_repository = PollSeriesRepository::new(db.clone());
+    let poll_repository = PollRepository::new(db.clone());
+    let revision_repository = PollRevisionRepository::new(db);
+
+    let mut interval = tokio::time::interval(Duration::from_secs(interval_secs));
+    loop {
+        interval.tick().await;
+
+        let due_series = match series_repository.get_due_series(Utc::now()).await {
+            Ok(due_series) => due_series,
+            Err(e) => {
+                error!("Failed to fetch due poll series: {}", e);
+                continue;
+            }
+        };
+
+        for series in due_series {
+            if let Err(e) = generate_instance(
+                &series_repository,
+                &poll_repository,
+                &revision_repository,
+                &series,
+            )
+            .await
+            {
+                error!(
+                    "Failed to generate instance of series {}: {}",
+                    series.seriesId, e
+                );
+            }
+        }
+    }
+}
+
+async fn generate_instance(
+    series_repository: &PollSeriesRepository,
+    poll_repository: &PollRepository,
+    revision_repository: &PollRevisionRepository,
+    series: &PollSeries,
+) -> Result<(), AppError> {
+    let now = Utc::now();
+    let start = DateTime::<Utc>::from_timestamp_millis(series.nextRunAt.timestamp_millis())
+        .ok_or(AppError::Unknown)?;
+    let end = recurrence::occurrence_end(&series.recurrence, start);
+
+    // After downtime, jump straight to the next future occurrence instead of catching up
+    let next_run_at = recurrence::next_occurrence(&series.recurrence, start.max(now))
+        .map_err(|e| AppError::Poll(PollsError::InvalidConfiguration(e)))?;
+
+    if !series_repository.claim_run(series, next_run_at).await? {
+        return Ok(());
+    }
+
+    if end <= now {
+        warn!(
+            "Skipping missed occurrence {} of series {}",
+            start, series.seriesId
+        );
+        return Ok(());
+    }
+
+    let params = HashMap::from([(
+        "date".to_string(),
+        recurrence::local_date(&series.recurrence, start),
+    )]);
+    let name = template::render(&series.name, &params).unwrap_or_else(|_| series.name.clone());
+
+    let poll = poll_repository
+        .create_poll(CreatePollDTO {
+            name,
+            isMulti: series.isMulti,
+            startDate: start,
+            endDate: end,
+            createdBy: series.createdBy.clone(),
+            options: series.options.clone(),
+            isPrivate: false,
+            orgId: None,
+            targetGroups: vec![],
+            allowGuests: false,
+            guestFingerprinting: false,
+            resultsVisibility: ResultsVisibility::default(),
+            shuffleOptions: false,
+            seriesId: Some(series.seriesId.clone()),
+        })
+        .await?;
+    revision_repository.record_creation(&poll).await?;
+
+    info!(
+        "Generated poll {} for series {}",
+        poll.poll_id, series.seriesId
+    );
+
+    Ok(())
+}
@@ -0,0 +1,555 @@
+use std::{
+    f64::consts::PI,
+    fmt::Write,
+    sync::{Arc, OnceLock},
+};
+
+use resvg::{
+    tiny_skia::{Pixmap, Transform},
+    usvg::{fontdb, Options, Tree},
+};
+
+use crate::{
+    dtos::{
+        requests::{ChartKind, ChartLabels, ChartTheme},
+        responses::PollResponseDTO,
+    },
+    error::AppError,
+};
+
+// Fonts to try in turn, servers rarely have the same ones installed
+const FONT_FAMILY: &str = "DejaVu Sans, Liberation Sans, Arial, Helvetica, sans-serif";
+
+// Colors of the options, repeated when a poll has more
+const PALETTE: [&str; 8] = [
+    "#6366f1", "#f59e0b", "#10b981", "#ef4444", "#06b6d4", "#ec4899", "#84cc16", "#8b5cf6",
+];
+
+pub struct ChartOptions {
+    pub kind: ChartKind,
+    pub theme: ChartTheme,
+    pub labels: ChartLabels,
+    pub width: u32,
+    pub height: u32,
+}
+
+struct Colors {
+    background: &'static str,
+    text: &'static str,
+    muted: &'static str,
+    track: &'static str, // Behind bars, and the pie of a poll without votes
+}
+
+impl ChartTheme {
+    fn colors(&self) -> Colors {
+        match self {
+            ChartTheme::Light => Colors {
+                background: "#ffffff",
+                text: "#1f2937",
+                muted: "#6b7280",
+                track: "#e5e7eb",
+            },
+            ChartTheme::Dark => Colors {
+                background: "#111827",
+                text: "#f9fafb",
+                muted: "#9ca3af",
+                track: "#374151",
+            },
+        }
+    }
+}
+
+// Sizes of the elements of a chart, scaled along with it
+struct Layout {
+    width: f64,
+    height: f64,
+    padding: f64,
+    title_size: f64,
+    label_size: f64,
+    top: f64, // Where the plot starts, below the title
+}
+
+impl Layout {
+    fn new(width: u32, height: u32) -> Self {
+        let (width, height) = (width as f64, height as f64);
+        let scale = width.min(height) / 500.0;
+        let padding = 24.0 * scale;
+        let title_size = 22.0 * scale;
+        let label_size = 14.0 * scale;
+
+        Self {
+            width,
+            height,
+            padding,
+            title_size,
+            label_size,
+            top: padding + title_size + label_size * 2.6,
+        }
+    }
+
+    // How many characters of text at `size` fit in `width`, roughly
+    fn fitting_chars(width: f64, size: f64) -> usize {
+        (width / (size * 0.6)).max(1.0) as usize
+    }
+}
+
+// What's charted of an option
+struct Slice {
+    name: String,
+    votes: i32,
+    color: &'static str,
+}
+
+/// Renders the results of the poll as an SVG document of the requested size. Options are
+/// charted in their display order, hidden vote counts as zero.
+pub fn render_svg(poll: &PollResponseDTO, options: &ChartOptions) -> String {
+    let colors = options.theme.colors();
+    let layout = Layout::new(options.width, options.height);
+
+    let mut poll_options = poll.options.iter().collect::<Vec<_>>();
+    poll_options.sort_by_key(|option| option.display_order);
+    let slices = poll_options
+        .iter()
+        .enumerate()
+        .map(|(index, option)| Slice {
+            name: option.option_name.clone(),
+            votes: option.votes.unwrap_or(0).max(0),
+            color: PALETTE[index % PALETTE.len()],
+        })
+        .collect::<Vec<_>>();
+    let total = slices.iter().map(|slice| slice.votes as i64).sum::<i64>();
+
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}" font-family="{FONT_FAMILY}">"#,
+        w = options.width,
+        h = options.height,
+    );
+    let _ = write!(
+        svg,
+        r#"<rect width="100%" height="100%" fill="{}"/>"#,
+        colors.background
+    );
+
+    let title_chars = Layout::fitting_chars(layout.width - 2.0 * layout.padding, layout.title_size);
+    text(
+        &mut svg,
+        layout.padding,
+        layout.padding + layout.title_size,
+        layout.title_size,
+        colors.text,
+        &truncate(&poll.name, title_chars),
+        "font-weight=\"bold\"",
+    );
+    let subtitle = match total {
+        1 => String::from("1 vote"),
+        total => format!("{} votes", total),
+    };
+    text(
+        &mut svg,
+        layout.padding,
+        layout.padding + layout.title_size + layout.label_size * 1.6,
+        layout.label_size,
+        colors.muted,
+        &subtitle,
+        "",
+    );
+
+    match options.kind {
+        ChartKind::Bar => bars(&mut svg, &layout, &colors, &slices, total, options.labels),
+        ChartKind::Pie => pie(&mut svg, &layout, &colors, &slices, total, options.labels),
+    }
+
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Rasterises a chart from `render_svg`, at the size it was rendered for
+pub fn render_png(svg: &str) -> Result<Vec<u8>, AppError> {
+    let options = Options {
+        fontdb: system_fonts(),
+        ..Options::default()
+    };
+    let tree = Tree::from_str(svg, &options).map_err(|_| AppError::Unknown)?;
+
+    let size = tree.size().to_int_size();
+    let mut pixmap = Pixmap::new(size.width(), size.height()).ok_or(AppError::Unknown)?;
+    resvg::render(&tree, Transform::default(), &mut pixmap.as_mut());
+
+    pixmap.encode_png().map_err(|_| AppError::Unknown)
+}
+
+// Loading the installed fonts takes a while, so it's only done once
+fn system_fonts() -> Arc<fontdb::Database> {
+    static FONTS: OnceLock<Arc<fontdb::Database>> = OnceLock::new();
+
+    FONTS
+        .get_or_init(|| {
+            let mut fonts = fontdb::Database::new();
+            fonts.load_system_fonts();
+            Arc::new(fonts)
+        })
+        .clone()
+}
+
+// One row per option, the name above a bar scaled to the most voted option
+fn bars(
+    svg: &mut String,
+    layout: &Layout,
+    colors: &Colors,
+    slices: &[Slice],
+    total: i64,
+    labels: ChartLabels,
+) {
+    if slices.is_empty() {
+        return;
+    }
+
+    let row = (layout.height - layout.padding - layout.top) / slices.len() as f64;
+    let size = layout.label_size.min(row * 0.3);
+    let bar_height = ((row - size * 1.4) * 0.7).min(size * 2.0);
+    let track_width = layout.width - 2.0 * layout.padding - size * 5.0;
+    let most_votes = slices.iter().map(|slice| slice.votes).max().unwrap_or(0);
+
+    for (index, slice) in slices.iter().enumerate() {
+        let row_top = layout.top + row * index as f64;
+        let bar_top = row_top + size * 1.4;
+        let bar_width = match most_votes {
+            0 => 0.0,
+            most_votes => track_width * slice.votes as f64 / most_votes as f64,
+        };
+
+        text(
+            svg,
+            layout.padding,
+            row_top + size,
+            size,
+            colors.text,
+            &truncate(&slice.name, Layout::fitting_chars(track_width, size)),
+            "",
+        );
+        let _ = write!(
+            svg,
+            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" rx="{:.1}" fill="{}"/>"#,
+            layout.padding,
+            bar_top,
+            track_width,
+            bar_height,
+            bar_height / 4.0,
+            colors.track
+        );
+        if bar_width > 0.0 {
+            let _ = write!(
+                svg,
+                r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" rx="{:.1}" fill="{}"/>"#,
+                layout.padding,
+                bar_top,
+                bar_width,
+                bar_height,
+                bar_height / 4.0,
+                slice.color
+            );
+        }
+        text(
+            svg,
+            layout.padding + track_width + size * 0.5,
+            bar_top + bar_height / 2.0 + size * 0.35,
+            size,
+            colors.muted,
+            &label(slice.votes, total, labels),
+            "",
+        );
+    }
+}
+
+// A pie on the left, its legend on the right
+fn pie(
+    svg: &mut String,
+    layout: &Layout,
+    colors: &Colors,
+    slices: &[Slice],
+    total: i64,
+    labels: ChartLabels,
+) {
+    let plot_height = layout.height - layout.padding - layout.top;
+    let radius = (plot_height.min((layout.width - 2.0 * layout.padding) * 0.45) / 2.0).max(1.0);
+    let (cx, cy) = (layout.padding + radius, layout.top + plot_height / 2.0);
+
+    let voted = slices
+        .iter()
+        .filter(|slice| slice.votes > 0)
+        .collect::<Vec<_>>();
+    match voted.as_slice() {
+        // A single arc can't draw a full circle
+        [] | [_] => {
+            let fill = voted.first().map_or(colors.track, |slice| slice.color);
+            let _ = write!(
+                svg,
+                r#"<circle cx="{:.1}" cy="{:.1}" r="{:.1}" fill="{}"/>"#,
+                cx, cy, radius, fill
+            );
+        }
+        voted => {
+            let mut angle = -PI / 2.0; // Starting at the top
+            for slice in voted {
+                let sweep = 2.0 * PI * slice.votes as f64 / total as f64;
+                let (x1, y1) = (cx + radius * angle.cos(), cy + radius * angle.sin());
+                angle += sweep;
+                let (x2, y2) = (cx + radius * angle.cos(), cy + radius * angle.sin());
+                let _ = write!(
+                    svg,
+                    r#"<path d="M{:.1},{:.1} L{:.1},{:.1} A{:.1},{:.1} 0 {} 1 {:.1},{:.1} Z" fill="{}" stroke="{}" stroke-width="{:.1}"/>"#,
+                    cx,
+                    cy,
+                    x1,
+                    y1,
+                    radius,
+                    radius,
+                    u8::from(sweep > PI),
+                    x2,
+                    y2,
+                    slice.color,
+                    colors.background,
+                    layout.label_size / 8.0
+                );
+            }
+        }
+    }
+
+    if slices.is_empty() {
+        return;
+    }
+    let legend_x = cx + radius + layout.padding * 1.5;
+    let row = (layout.label_size * 1.8).min(plot_height / slices.len() as f64);
+    let size = layout.label_size.min(row / 1.4);
+    let legend_top = cy - row * slices.len() as f64 / 2.0;
+    let text_x = legend_x + size * 1.5;
+    let text_chars = Layout::fitting_chars(layout.width - layout.padding - text_x, size);
+
+    for (index, slice) in slices.iter().enumerate() {
+        let row_top = legend_top + row * index as f64;
+        let _ = write!(
+            svg,
+            r#"<rect x="{:.1}" y="{:.1}" width="{:.1}" height="{:.1}" rx="{:.1}" fill="{}"/>"#,
+            legend_x,
+            row_top + (row - size) / 2.0,
+            size,
+            size,
+            size / 4.0,
+            slice.color
+        );
+        let line = format!("{} · {}", slice.name, label(slice.votes, total, labels));
+        text(
+            svg,
+            text_x,
+            row_top + row / 2.0 + size * 0.35,
+            size,
+            colors.text,
+            &truncate(&line, text_chars),
+            "",
+        );
+    }
+}
+
+fn label(votes: i32, total: i64, labels: ChartLabels) -> String {
+    match labels {
+        ChartLabels::Absolute => votes.to_string(),
+        ChartLabels::Percentage if total == 0 => String::from("0%"),
+        ChartLabels::Percentage => {
+            format!("{:.0}%", votes as f64 * 100.0 / total as f64)
+        }
+    }
+}
+
+fn text(svg: &mut String, x: f64, y: f64, size: f64, fill: &str, content: &str, extra: &str) {
+    let _ = write!(
+        svg,
+        r#"<text x="{:.1}" y="{:.1}" font-size="{:.1}" fill="{}" {}>{}</text>"#,
+        x,
+        y,
+        size,
+        fill,
+        extra,
+        escape(content)
+    );
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_string();
+    }
+
+    let mut truncated = text
+        .chars()
+        .take(max_chars.saturating_sub(1))
+        .collect::<String>();
+    truncated.push('…');
+    truncated
+}
+
+// Option names are user input, they must not break out of the text element
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dtos::responses::PollOptionResponseDTO, models::poll::ResultsVisibility};
+
+    fn poll(name: &str, votes: &[(&str, Option<i32>)]) -> PollResponseDTO {
+        PollResponseDTO {
+            poll_id: "poll".to_string(),
+            created_by: "owner".to_string(),
+            name: name.to_string(),
+            is_multi: false,
+            is_paused: false,
+            is_closed: false,
+            start_date: String::new(),
+            end_date: String::new(),
+            options: votes
+                .iter()
+                .enumerate()
+                .map(|(index, (option_name, votes))| PollOptionResponseDTO {
+                    option_id: index.to_string(),
+                    option_name: option_name.to_string(),
+                    votes: *votes,
+                    display_order: index as i32,
+                })
+                .collect(),
+            voted_by: vec![],
+            series_id: None,
+            org_id: None,
+            target_groups: vec![],
+            allow_guests: false,
+            guest_fingerprinting: false,
+            results_visibility: ResultsVisibility::Always,
+            results_hidden: false,
+            shuffle_options: false,
+            has_password: false,
+            is_private: false,
+            join_code: None,
+        }
+    }
+
+    fn options(kind: ChartKind, labels: ChartLabels) -> ChartOptions {
+        ChartOptions {
+            kind,
+            theme: ChartTheme::Light,
+            labels,
+            width: 400,
+            height: 300,
+        }
+    }
+
+    #[test]
+    fn labels_show_shares_or_counts() {
+        assert_eq!(label(1, 3, ChartLabels::Percentage), "33%");
+        assert_eq!(label(2, 3, ChartLabels::Percentage), "67%");
+        assert_eq!(label(3, 3, ChartLabels::Percentage), "100%");
+        assert_eq!(label(2, 3, ChartLabels::Absolute), "2");
+    }
+
+    #[test]
+    fn polls_without_votes_are_labelled_zero() {
+        assert_eq!(label(0, 0, ChartLabels::Percentage), "0%");
+        assert_eq!(label(0, 0, ChartLabels::Absolute), "0");
+
+        let svg = render_svg(
+            &poll("Empty", &[("Yes", Some(0)), ("No", Some(0))]),
+            &options(ChartKind::Bar, ChartLabels::Percentage),
+        );
+        assert!(svg.contains(">0 votes<"));
+        assert!(!svg.contains("NaN"));
+        assert!(!svg.contains(PALETTE[0])); // No bar is filled
+    }
+
+    #[test]
+    fn hidden_counts_are_charted_as_zero() {
+        let svg = render_svg(
+            &poll("Hidden", &[("Yes", None), ("No", Some(2))]),
+            &options(ChartKind::Bar, ChartLabels::Absolute),
+        );
+
+        assert!(svg.contains(">2 votes<"));
+        assert!(svg.contains(">0<"));
+    }
+
+    #[test]
+    fn pie_without_votes_is_a_blank_circle() {
+        let svg = render_svg(
+            &poll("Empty", &[("Yes", Some(0)), ("No", Some(0))]),
+            &options(ChartKind::Pie, ChartLabels::Percentage),
+        );
+
+        assert!(svg.contains(&format!("fill=\"{}\"/>", ChartTheme::Light.colors().track)));
+        assert!(!svg.contains("<path"));
+    }
+
+    #[test]
+    fn pie_with_a_single_voted_option_is_a_full_circle() {
+        // A single arc from a point back to itself would draw nothing
+        let svg = render_svg(
+            &poll("One", &[("Yes", Some(0)), ("No", Some(4))]),
+            &options(ChartKind::Pie, ChartLabels::Percentage),
+        );
+
+        assert!(svg.contains("<circle cx="));
+        assert!(svg.contains(&format!("fill=\"{}\"/>", PALETTE[1])));
+        assert!(!svg.contains("<path"));
+        assert!(svg.contains("No · 100%"));
+    }
+
+    #[test]
+    fn pie_slices_cover_the_voted_options() {
+        let svg = render_svg(
+            &poll(
+                "Split",
+                &[("Yes", Some(3)), ("No", Some(1)), ("Maybe", Some(0))],
+            ),
+            &options(ChartKind::Pie, ChartLabels::Percentage),
+        );
+
+        // The larger slice takes the long way round the circle
+        assert_eq!(svg.matches("<path").count(), 2);
+        assert!(svg.contains(" 0 1 1 "));
+        assert!(svg.contains(" 0 0 1 "));
+        assert!(svg.contains("Maybe · 0%"));
+    }
+
+    #[test]
+    fn user_text_is_escaped() {
+        let svg = render_svg(
+            &poll("<script> & \"quotes\"", &[("a < b", Some(1))]),
+            &options(ChartKind::Bar, ChartLabels::Percentage),
+        );
+
+        assert!(svg.contains("&lt;script&gt; &amp; &quot;quotes&quot;"));
+        assert!(svg.contains("a &lt; b"));
+        assert!(!svg.contains("<script>"));
+    }
+
+    #[test]
+    fn long_text_is_truncated() {
+        assert_eq!(truncate("short", 10), "short");
+        assert_eq!(truncate("much too long", 5), "much…");
+        assert_eq!(truncate("äöüäöü", 4), "äöü…");
+    }
+
+    #[test]
+    fn png_has_the_requested_size() {
+        let svg = render_svg(
+            &poll("Sized", &[("Yes", Some(1)), ("No", Some(2))]),
+            &options(ChartKind::Bar, ChartLabels::Percentage),
+        );
+        let png_bytes = render_png(&svg).unwrap();
+
+        let decoder = png::Decoder::new(png_bytes.as_slice());
+        let info = decoder.read_info().unwrap().info().clone();
+        assert_eq!((info.width, info.height), (400, 300));
+    }
+}
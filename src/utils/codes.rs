@@ -0,0 +1,30 @@
+use rand::{distributions::Alphanumeric, Rng};
+
+/// Long random code for invite links, hard to guess so it can be shared as a secret
+pub fn generate_invite_code() -> String {
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(24)
+        .map(char::from)
+        .collect()
+}
+
+// No 0/O, 1/I/L so codes can be read off a projector without ambiguity
+const JOIN_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ23456789";
+pub const JOIN_CODE_LENGTH: usize = 6;
+
+/// Short code attendees type in to find a live poll
+pub fn generate_join_code() -> String {
+    let mut rng = rand::thread_rng();
+    (0..JOIN_CODE_LENGTH)
+        .map(|_| JOIN_CODE_ALPHABET[rng.gen_range(0..JOIN_CODE_ALPHABET.len())] as char)
+        .collect()
+}
+
+/// Upper-cases and strips separators so "abc-123" finds "ABC123"
+pub fn normalize_join_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
@@ -0,0 +1,53 @@
+use std::net::{IpAddr, SocketAddr};
+
+use axum::http::{header::USER_AGENT, HeaderMap};
+use sha2::{Digest, Sha256};
+
+use crate::config::proxy::trusted_proxies;
+
+/// Address of the client. `X-Forwarded-For` is only read when the connection comes from a
+/// trusted proxy, and then from the right, since clients can prepend whatever they like.
+pub fn client_ip(headers: &HeaderMap, addr: SocketAddr) -> String {
+    resolve_client_ip(headers, addr.ip(), trusted_proxies()).to_string()
+}
+
+fn resolve_client_ip(headers: &HeaderMap, peer: IpAddr, trusted: &[IpAddr]) -> IpAddr {
+    if !trusted.contains(&peer) {
+        return peer;
+    }
+
+    let mut client = peer;
+    let hops = headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .collect::<Vec<_>>();
+    // Each trusted proxy appended the address it got the request from
+    for hop in hops.iter().rev() {
+        match hop.trim().parse::<IpAddr>() {
+            Ok(ip) if trusted.contains(&ip) => client = ip,
+            Ok(ip) => return ip,
+            Err(_) => break,
+        }
+    }
+
+    client
+}
+
+/// Hash of the client's address and user agent, scoped to a poll so it can't be
+/// correlated across polls. Raw addresses are never stored.
+pub fn device_fingerprint(poll_id: &str, headers: &HeaderMap, addr: SocketAddr) -> String {
+    let user_agent = headers
+        .get(USER_AGENT)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    let mut hasher = Sha256::new();
+    hasher.update(poll_id.as_bytes());
+    hasher.update(b"|");
+    hasher.update(client_ip(headers, addr).as_bytes());
+    hasher.update(b"|");
+    hasher.update(user_agent.as_bytes());
+    format!("{:x}", hasher.finalize())
+}
@@ -41,3 +41,141 @@ pub fn verify_token(token: &str, secret: &[u8]) -> Result<Claims, JwtError> {
         })?;
     Ok(token_data.claims)
 }
+
+/// Claims of a guest token, only valid for voting on a single poll
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GuestClaims {
+    pub sub: String,  // guest id, stored in votedBy
+    pub poll: String, // poll the token is bound to
+    pub exp: i64,
+    pub iat: i64,
+}
+
+// Guest, poll access and live stream tokens are signed with keys of their own so they never pass
+// `require_auth` and can't be swapped for each other
+fn scoped_secret(secret: &[u8], scope: &[u8]) -> Vec<u8> {
+    [secret, b":", scope].concat()
+}
+
+pub fn create_guest_token(
+    guest_id: &str,
+    poll_id: &str,
+    expires_at: i64,
+    secret: &[u8],
+) -> Result<String, JwtError> {
+    let claims = GuestClaims {
+        sub: guest_id.to_string(),
+        poll: poll_id.to_string(),
+        iat: Utc::now().timestamp(),
+        exp: expires_at,
+    };
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(&scoped_secret(secret, b"guest")),
+    )
+    .map_err(|_| JwtError::TokenCreationError)
+}
+
+pub fn verify_guest_token(token: &str, secret: &[u8]) -> Result<GuestClaims, JwtError> {
+    let validation = Validation::new(Algorithm::HS256);
+    let token_data = decode::<GuestClaims>(
+        token,
+        &DecodingKey::from_secret(&scoped_secret(secret, b"guest")),
+        &validation,
+    )
+    .map_err(|e| match e.kind() {
+        jsonwebtoken::errors::ErrorKind::ExpiredSignature => JwtError::TokenExpired,
+        jsonwebtoken::errors::ErrorKind::InvalidSignature => JwtError::InvalidSignature,
+        _ => JwtError::InvalidTokenFormat,
+    })?;
+    Ok(token_data.claims)
+}
+
+/// Claims of a token proving the poll password was entered
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PollAccessClaims {
+    pub sub: String, // poll id
+    pub exp: i64,
+    pub iat: i64,
+}
+
+pub fn create_poll_access_token(
+    poll_id: &str,
+    ttl: Duration,
+    secret: &[u8],
+) -> Result<String, JwtError> {
+    let now = Utc::now();
+    let claims = PollAccessClaims {
+        sub: poll_id.to_string(),
+        iat: now.timestamp(),
+        exp: (now + ttl).timestamp(),
+    };
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(&scoped_secret(secret, b"poll-access")),
+    )
+    .map_err(|_| JwtError::TokenCreationError)
+}
+
+pub fn verify_poll_access_token(token: &str, secret: &[u8]) -> Result<PollAccessClaims, JwtError> {
+    let validation = Validation::new(Algorithm::HS256);
+    let token_data = decode::<PollAccessClaims>(
+        token,
+        &DecodingKey::from_secret(&scoped_secret(secret, b"poll-access")),
+        &validation,
+    )
+    .map_err(|e| match e.kind() {
+        jsonwebtoken::errors::ErrorKind::ExpiredSignature => JwtError::TokenExpired,
+        jsonwebtoken::errors::ErrorKind::InvalidSignature => JwtError::InvalidSignature,
+        _ => JwtError::InvalidTokenFormat,
+    })?;
+    Ok(token_data.claims)
+}
+
+/// Claims of a token for opening the live results stream of a single poll. Short-lived, as
+/// it ends up in URLs since `EventSource` can't send headers.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LiveStreamClaims {
+    pub sub: String,  // user_id
+    pub poll: String, // poll the token is bound to
+    pub exp: i64,
+    pub iat: i64,
+}
+
+pub fn create_live_stream_token(
+    user_id: &str,
+    poll_id: &str,
+    ttl: Duration,
+    secret: &[u8],
+) -> Result<String, JwtError> {
+    let now = Utc::now();
+    let claims = LiveStreamClaims {
+        sub: user_id.to_string(),
+        poll: poll_id.to_string(),
+        iat: now.timestamp(),
+        exp: (now + ttl).timestamp(),
+    };
+    encode(
+        &Header::default(),
+        &claims,
+        &EncodingKey::from_secret(&scoped_secret(secret, b"live-stream")),
+    )
+    .map_err(|_| JwtError::TokenCreationError)
+}
+
+pub fn verify_live_stream_token(token: &str, secret: &[u8]) -> Result<LiveStreamClaims, JwtError> {
+    let validation = Validation::new(Algorithm::HS256);
+    let token_data = decode::<LiveStreamClaims>(
+        token,
+        &DecodingKey::from_secret(&scoped_secret(secret, b"live-stream")),
+        &validation,
+    )
+    .map_err(|e| match e.kind() {
+        jsonwebtoken::errors::ErrorKind::ExpiredSignature => JwtError::TokenExpired,
+        jsonwebtoken::errors::ErrorKind::InvalidSignature => JwtError::InvalidSignature,
+        _ => JwtError::InvalidTokenFormat,
+    })?;
+    Ok(token_data.claims)
+}
@@ -1 +1,11 @@
+pub mod chart;
+pub mod codes;
+pub mod fingerprint;
 pub mod jwt;
+pub mod password;
+pub mod poll_access;
+pub mod qr;
+pub mod recurrence;
+pub mod template;
+pub mod validation;
+pub mod votes;
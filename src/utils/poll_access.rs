@@ -0,0 +1,26 @@
+use std::convert::Infallible;
+
+use axum::{extract::FromRequestParts, http::request::Parts};
+
+pub const POLL_ACCESS_HEADER: &str = "x-poll-access-token";
+
+/// Token from the `X-Poll-Access-Token` header, proving the password of a protected poll
+/// was entered. Missing on requests for polls without a password.
+pub struct PollAccessToken(pub Option<String>);
+
+impl<S> FromRequestParts<S> for PollAccessToken
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        Ok(PollAccessToken(
+            parts
+                .headers
+                .get(POLL_ACCESS_HEADER)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string),
+        ))
+    }
+}
@@ -0,0 +1,63 @@
+use qrcode::{render::svg, EcLevel, QrCode};
+
+use crate::error::{AppError, PollsError};
+
+// Blank border the QR spec asks for around the code, in modules
+const QUIET_ZONE: usize = 4;
+
+fn encode(data: &str, ec_level: EcLevel) -> Result<QrCode, AppError> {
+    QrCode::with_error_correction_level(data, ec_level).map_err(|e| {
+        AppError::Poll(PollsError::InvalidConfiguration(format!(
+            "Could not encode QR code: {}",
+            e
+        )))
+    })
+}
+
+/// Renders `data` as an SVG document at least `size` pixels wide
+pub fn render_svg(data: &str, size: u32, ec_level: EcLevel) -> Result<String, AppError> {
+    let code = encode(data, ec_level)?;
+
+    Ok(code
+        .render::<svg::Color>()
+        .min_dimensions(size, size)
+        .quiet_zone(true)
+        .build())
+}
+
+/// Renders `data` as a greyscale PNG, modules are scaled to whole pixels so the
+/// image can be slightly smaller than `size`, but never below one pixel per module
+pub fn render_png(data: &str, size: u32, ec_level: EcLevel) -> Result<Vec<u8>, AppError> {
+    let code = encode(data, ec_level)?;
+    let modules = code.width();
+    let colors = code.to_colors();
+
+    let total_modules = modules + 2 * QUIET_ZONE;
+    let scale = (size as usize / total_modules).max(1);
+    let dimension = total_modules * scale;
+
+    let mut pixels = vec![255u8; dimension * dimension];
+    for (index, color) in colors.iter().enumerate() {
+        if *color != qrcode::Color::Dark {
+            continue;
+        }
+
+        let x0 = (index % modules + QUIET_ZONE) * scale;
+        let y0 = (index / modules + QUIET_ZONE) * scale;
+        for y in y0..y0 + scale {
+            pixels[y * dimension + x0..y * dimension + x0 + scale].fill(0);
+        }
+    }
+
+    let mut png_bytes = Vec::new();
+    let mut encoder = png::Encoder::new(&mut png_bytes, dimension as u32, dimension as u32);
+    encoder.set_color(png::ColorType::Grayscale);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&pixels))
+        .map_err(|_| AppError::Unknown)?;
+
+    Ok(png_bytes)
+}
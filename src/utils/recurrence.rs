@@ -0,0 +1,243 @@
+use std::str::FromStr;
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::models::poll_series::{Frequency, RecurrenceRule};
+
+// Every supported frequency repeats at least once within this many days
+const MAX_LOOKAHEAD_DAYS: i64 = 62;
+
+/// Checks the rule can produce occurrences, returning a description of the problem otherwise
+pub fn validate(rule: &RecurrenceRule) -> Result<(), String> {
+    Tz::from_str(&rule.timezone).map_err(|_| format!("Unknown time zone '{}'", rule.timezone))?;
+    parse_start_time(&rule.startTime)?;
+
+    if rule.durationMinutes <= 0 {
+        return Err("durationMinutes must be positive".to_string());
+    }
+
+    match &rule.frequency {
+        Frequency::Daily => Ok(()),
+        Frequency::Weekly { weekdays } if weekdays.is_empty() => {
+            Err("Weekly recurrence needs at least one weekday".to_string())
+        }
+        Frequency::Weekly { .. } => Ok(()),
+        Frequency::Monthly { dayOfMonth } if !(1..=31).contains(dayOfMonth) => {
+            Err("dayOfMonth must be between 1 and 31".to_string())
+        }
+        Frequency::Monthly { .. } => Ok(()),
+    }
+}
+
+/// Finds the first occurrence of the rule that starts strictly after `after`.
+/// Occurrences are computed on the wall clock of the rule's time zone, so they stay at the
+/// same local time across DST changes.
+pub fn next_occurrence(
+    rule: &RecurrenceRule,
+    after: DateTime<Utc>,
+) -> Result<DateTime<Utc>, String> {
+    let tz = Tz::from_str(&rule.timezone)
+        .map_err(|_| format!("Unknown time zone '{}'", rule.timezone))?;
+    let start_time = parse_start_time(&rule.startTime)?;
+    let first_day = after.with_timezone(&tz).date_naive();
+
+    (0..=MAX_LOOKAHEAD_DAYS)
+        .map(|offset| first_day + Duration::days(offset))
+        .filter(|day| matches_frequency(&rule.frequency, *day))
+        .filter_map(|day| local_to_utc(&tz, day, start_time))
+        .find(|occurrence| *occurrence > after)
+        .ok_or_else(|| "Recurrence rule never matches".to_string())
+}
+
+/// End of the instance starting at `start`
+pub fn occurrence_end(rule: &RecurrenceRule, start: DateTime<Utc>) -> DateTime<Utc> {
+    start + Duration::minutes(rule.durationMinutes)
+}
+
+/// Local calendar date of an occurrence, used to name instances
+pub fn local_date(rule: &RecurrenceRule, start: DateTime<Utc>) -> String {
+    match Tz::from_str(&rule.timezone) {
+        Ok(tz) => start.with_timezone(&tz).format("%Y-%m-%d").to_string(),
+        Err(_) => start.format("%Y-%m-%d").to_string(),
+    }
+}
+
+fn parse_start_time(start_time: &str) -> Result<NaiveTime, String> {
+    NaiveTime::parse_from_str(start_time, "%H:%M")
+        .map_err(|_| format!("startTime '{}' must be formatted as HH:MM", start_time))
+}
+
+fn matches_frequency(frequency: &Frequency, day: NaiveDate) -> bool {
+    match frequency {
+        Frequency::Daily => true,
+        Frequency::Weekly { weekdays } => weekdays.contains(&day.weekday()),
+        Frequency::Monthly { dayOfMonth } => day.day() == (*dayOfMonth).min(days_in_month(day)),
+    }
+}
+
+fn days_in_month(day: NaiveDate) -> u32 {
+    let (year, month) = if day.month() == 12 {
+        (day.year() + 1, 1)
+    } else {
+        (day.year(), day.month() + 1)
+    };
+
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|first_of_next| first_of_next.pred_opt())
+        .map(|last_day| last_day.day())
+        .unwrap_or(28)
+}
+
+fn local_to_utc(tz: &Tz, day: NaiveDate, time: NaiveTime) -> Option<DateTime<Utc>> {
+    let local = day.and_time(time);
+    tz.from_local_datetime(&local)
+        .earliest()
+        // Start times inside a DST gap move forward by the size of the gap
+        .or_else(|| {
+            tz.from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Weekday;
+
+    use super::*;
+
+    fn rule(frequency: Frequency, start_time: &str) -> RecurrenceRule {
+        RecurrenceRule {
+            frequency,
+            startTime: start_time.to_string(),
+            durationMinutes: 60,
+            timezone: "Europe/Berlin".to_string(),
+        }
+    }
+
+    fn utc(value: &str) -> DateTime<Utc> {
+        value.parse().unwrap()
+    }
+
+    #[test]
+    fn keeps_local_time_across_dst_start() {
+        let rule = rule(Frequency::Daily, "09:00");
+
+        // 09:00 is 08:00 UTC in winter and 07:00 UTC once summer time starts on the 29th
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-03-27T12:00:00Z")),
+            Ok(utc("2026-03-28T08:00:00Z"))
+        );
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-03-28T08:00:00Z")),
+            Ok(utc("2026-03-29T07:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn start_time_in_dst_gap_moves_forward() {
+        // 02:30 doesn't exist on the 29th, clocks jump from 02:00 to 03:00
+        let rule = rule(Frequency::Daily, "02:30");
+
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-03-28T12:00:00Z")),
+            Ok(utc("2026-03-29T01:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn start_time_in_dst_overlap_takes_the_earlier_one() {
+        // 02:30 happens twice on October 25th, first in summer time
+        let rule = rule(Frequency::Daily, "02:30");
+
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-10-24T12:00:00Z")),
+            Ok(utc("2026-10-25T00:30:00Z"))
+        );
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-10-25T00:30:00Z")),
+            Ok(utc("2026-10-26T01:30:00Z"))
+        );
+    }
+
+    #[test]
+    fn weekly_picks_the_next_listed_weekday() {
+        let rule = rule(
+            Frequency::Weekly {
+                weekdays: vec![Weekday::Mon, Weekday::Thu],
+            },
+            "10:00",
+        );
+
+        // 2026-06-02 is a Tuesday
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-06-02T12:00:00Z")),
+            Ok(utc("2026-06-04T08:00:00Z"))
+        );
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-06-04T08:00:00Z")),
+            Ok(utc("2026-06-08T08:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn monthly_falls_back_to_the_last_day_of_short_months() {
+        let rule = rule(Frequency::Monthly { dayOfMonth: 31 }, "12:00");
+
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-01-31T11:00:00Z")),
+            Ok(utc("2026-02-28T11:00:00Z"))
+        );
+        assert_eq!(
+            next_occurrence(&rule, utc("2028-02-01T00:00:00Z")),
+            Ok(utc("2028-02-29T11:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn monthly_finds_the_next_month_within_the_lookahead() {
+        let rule = rule(Frequency::Monthly { dayOfMonth: 1 }, "00:00");
+
+        // Right after an occurrence the next one is a whole month away
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-01-01T00:00:00Z")),
+            Ok(utc("2026-01-31T23:00:00Z"))
+        );
+        assert_eq!(
+            next_occurrence(&rule, utc("2026-01-31T23:00:00Z")),
+            Ok(utc("2026-02-28T23:00:00Z"))
+        );
+    }
+
+    #[test]
+    fn rule_without_weekdays_never_matches() {
+        let rule = rule(Frequency::Weekly { weekdays: vec![] }, "10:00");
+
+        assert!(next_occurrence(&rule, utc("2026-06-02T12:00:00Z")).is_err());
+    }
+
+    #[test]
+    fn validate_rejects_broken_rules() {
+        assert!(validate(&rule(Frequency::Daily, "09:00")).is_ok());
+        assert!(validate(&rule(Frequency::Daily, "9am")).is_err());
+        assert!(validate(&rule(Frequency::Weekly { weekdays: vec![] }, "09:00")).is_err());
+        assert!(validate(&rule(Frequency::Monthly { dayOfMonth: 0 }, "09:00")).is_err());
+        assert!(validate(&rule(Frequency::Monthly { dayOfMonth: 32 }, "09:00")).is_err());
+
+        let mut no_duration = rule(Frequency::Daily, "09:00");
+        no_duration.durationMinutes = 0;
+        assert!(validate(&no_duration).is_err());
+
+        let mut unknown_zone = rule(Frequency::Daily, "09:00");
+        unknown_zone.timezone = "Europe/Atlantis".to_string();
+        assert!(validate(&unknown_zone).is_err());
+    }
+
+    #[test]
+    fn local_date_uses_the_rule_time_zone() {
+        let rule = rule(Frequency::Daily, "00:30");
+
+        assert_eq!(local_date(&rule, utc("2026-03-28T23:30:00Z")), "2026-03-29");
+    }
+}
@@ -0,0 +1,48 @@
+use std::collections::HashMap;
+
+/// Replaces every `{{param}}` placeholder in `text` with its value from `params`.
+///
+/// # Returns
+/// The rendered text, or the name of the first placeholder without a value
+pub fn render(text: &str, params: &HashMap<String, String>) -> Result<String, String> {
+    let mut rendered = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(start) = rest.find("{{") {
+        let Some(len) = rest[start + 2..].find("}}") else {
+            break;
+        };
+
+        let key = rest[start + 2..start + 2 + len].trim();
+        let value = params.get(key).ok_or_else(|| key.to_string())?;
+
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(value);
+        rest = &rest[start + 2 + len + 2..];
+    }
+
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+/// Lists the distinct placeholder names used in `texts`, in order of appearance
+pub fn placeholders<'a>(texts: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut names: Vec<String> = Vec::new();
+
+    for text in texts {
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            let Some(len) = rest[start + 2..].find("}}") else {
+                break;
+            };
+
+            let key = rest[start + 2..start + 2 + len].trim().to_string();
+            if !names.contains(&key) {
+                names.push(key);
+            }
+            rest = &rest[start + 2 + len + 2..];
+        }
+    }
+
+    names
+}
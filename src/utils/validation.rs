@@ -0,0 +1,187 @@
+use std::collections::HashSet;
+
+use axum::{
+    extract::{FromRequest, FromRequestParts, Query, Request},
+    http::request::Parts,
+    response::{IntoResponse, Response},
+    Json,
+};
+use chrono::{DateTime, Utc};
+use serde::{de::DeserializeOwned, Serialize};
+
+use crate::{config::validation::ValidationLimits, error::AppError};
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub code: &'static str,
+    pub message: String,
+}
+
+/// Every problem found in a request, reported together so clients can fix them in one go
+#[derive(Debug, Clone, Default)]
+pub struct ValidationErrors {
+    pub errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn add(
+        &mut self,
+        field: impl Into<String>,
+        code: &'static str,
+        message: impl Into<String>,
+    ) {
+        self.errors.push(FieldError {
+            field: field.into(),
+            code,
+            message: message.into(),
+        });
+    }
+
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self)
+        }
+    }
+
+    /// Checks a required piece of text is not blank and fits the length limit
+    pub fn check_text(&mut self, field: &str, value: &str, max_length: usize) {
+        if value.trim().is_empty() {
+            self.add(field, "required", format!("{} must not be empty", field));
+        } else if value.chars().count() > max_length {
+            self.add(
+                field,
+                "tooLong",
+                format!("{} must be at most {} characters", field, max_length),
+            );
+        }
+    }
+
+    /// Checks the number of options, each option's text, and that no option is repeated
+    pub fn check_options<'a>(
+        &mut self,
+        field: &str,
+        options: impl ExactSizeIterator<Item = &'a str>,
+        limits: &ValidationLimits,
+    ) {
+        if options.len() < limits.min_options {
+            self.add(
+                field,
+                "tooFew",
+                format!("A poll needs at least {} options", limits.min_options),
+            );
+        } else if options.len() > limits.max_options {
+            self.add(
+                field,
+                "tooMany",
+                format!("A poll can have at most {} options", limits.max_options),
+            );
+        }
+
+        let mut seen = HashSet::new();
+        for (index, option) in options.enumerate() {
+            let option_field = format!("{}[{}]", field, index);
+            self.check_text(&option_field, option, limits.max_option_length);
+
+            if !option.trim().is_empty() && !seen.insert(option.trim().to_lowercase()) {
+                self.add(
+                    option_field,
+                    "duplicate",
+                    format!("Option '{}' is listed more than once", option.trim()),
+                );
+            }
+        }
+    }
+
+    pub fn check_dates(&mut self, start: DateTime<Utc>, end: DateTime<Utc>) {
+        if end <= start {
+            self.add(
+                "endDate",
+                "invalidPollDates",
+                "endDate must be after startDate",
+            );
+        }
+    }
+
+    /// New polls can't be over before anyone gets to vote
+    pub fn check_end_in_future(&mut self, end: DateTime<Utc>) {
+        if end <= Utc::now() {
+            self.add(
+                "endDate",
+                "invalidPollDates",
+                "endDate must be in the future",
+            );
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fields: Vec<&str> = self.errors.iter().map(|e| e.field.as_str()).collect();
+        write!(f, "invalid fields: {}", fields.join(", "))
+    }
+}
+
+/// Implemented by request DTOs that need checks beyond what deserialization enforces
+pub trait Validate {
+    fn validate(&self, limits: &ValidationLimits) -> Result<(), ValidationErrors>;
+}
+
+/// Drop-in replacement for `Json` that also runs the payload's `Validate` impl
+/// against the limits installed as an extension at startup.
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let limits = limits_of(req.extensions());
+        let Json(payload) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        payload
+            .validate(&limits)
+            .map_err(|errors| AppError::Validation(errors).into_response())?;
+
+        Ok(ValidatedJson(payload))
+    }
+}
+
+/// Same as `ValidatedJson`, for query parameters
+pub struct ValidatedQuery<T>(pub T);
+
+impl<T, S> FromRequestParts<S> for ValidatedQuery<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Response;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let limits = limits_of(&parts.extensions);
+        let Query(query) = Query::<T>::from_request_parts(parts, state)
+            .await
+            .map_err(IntoResponse::into_response)?;
+
+        query
+            .validate(&limits)
+            .map_err(|errors| AppError::Validation(errors).into_response())?;
+
+        Ok(ValidatedQuery(query))
+    }
+}
+
+// Installed with `Extension(ValidationLimits::from_env())` in main
+fn limits_of(extensions: &axum::http::Extensions) -> ValidationLimits {
+    extensions
+        .get::<ValidationLimits>()
+        .copied()
+        .unwrap_or_default()
+}
@@ -0,0 +1,4 @@
+/// Every user gets a single vote, on polls as well as on Q&A questions
+pub fn has_voted(voters: &[String], user_id: &str) -> bool {
+    voters.iter().any(|voter| voter == user_id)
+}